@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{bail, Context, Result};
+
+use crate::Answer;
+
+/// The name of the answers file inside of an inputs directory.
+pub const FILENAME: &str = "answers.toml";
+
+/// The known answers for a set of puzzle inputs.
+///
+/// These are stored as TOML with a table per day and a key per part:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1581
+/// part2 = 1618
+/// ```
+///
+/// Numbers too big for a TOML integer are written as strings, such as
+/// `part2 = "18446744073709551615"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    data: BTreeMap<(u8, u8), Answer>,
+}
+
+fn day_from_key(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+fn part_from_key(key: &str) -> Option<u8> {
+    key.strip_prefix("part")?.parse().ok()
+}
+
+impl Answers {
+    /// Creates an empty set of answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the answers from a TOML document.
+    pub fn parse(s: &str) -> Result<Self> {
+        let table: toml::Table = s.parse()?;
+        let mut data = BTreeMap::new();
+        for (key, parts) in table {
+            let day = day_from_key(&key).with_context(|| format!("invalid day `{}`", key))?;
+            let parts = parts
+                .as_table()
+                .with_context(|| format!("`{}` is not a table", key))?;
+            for (name, value) in parts {
+                let part = part_from_key(name)
+                    .with_context(|| format!("invalid part `{}` for `{}`", name, key))?;
+                let answer = match value {
+                    toml::Value::Integer(value) => Answer::Int(*value),
+                    toml::Value::String(value) => match value.parse::<u64>() {
+                        Ok(value) => Answer::from(value),
+                        Err(_) => Answer::Text(value.clone()),
+                    },
+                    _ => bail!("`{}.{}` is not an integer or a string", key, name),
+                };
+                data.insert((day, part), answer);
+            }
+        }
+        Ok(Self { data })
+    }
+
+    /// Loads the answers from a file.
+    ///
+    /// If the file doesn't exist then there are no known answers.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("could not parse {}", path.display()))
+    }
+
+    /// Returns the known answer for the given day and part.
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.data.get(&(day, part))
+    }
+
+    /// Sets the known answer for the given day and part.
+    pub fn insert(&mut self, day: u8, part: u8, answer: Answer) -> Option<Answer> {
+        self.data.insert((day, part), answer)
+    }
+
+    /// Returns the number of known answers.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if there are no known answers.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Checks an answer against the known one.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Status {
+        match self.get(day, part) {
+            None => Status::Missing,
+            Some(expected) if same_answer(expected, answer) => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+        }
+    }
+}
+
+// text answers tend to be drawn out so trailing whitespace shouldn't matter
+fn same_answer(expected: &Answer, actual: &Answer) -> bool {
+    match (expected, actual) {
+        (Answer::Text(lhs), Answer::Text(rhs)) => lhs
+            .trim_end()
+            .lines()
+            .map(str::trim_end)
+            .eq(rhs.trim_end().lines().map(str::trim_end)),
+        _ => expected == actual,
+    }
+}
+
+/// The result of checking an answer against the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the known one.
+    Pass,
+    /// The answer is different from the known one, which is given.
+    Fail(Answer),
+    /// There is no known answer.
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day01]
+part1 = 7
+part2 = 5
+
+[day13]
+part2 = """
+#####
+#...#
+#####
+"""
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(1, 1), Some(&Answer::Int(7)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::Int(5)));
+        assert_eq!(answers.get(2, 1), None);
+        assert!(matches!(answers.get(13, 2), Some(Answer::Text(_))));
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let answers = Answers::parse("[day01]\npart1 = \"18446744073709551615\"").unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Unsigned(u64::MAX)));
+        assert_eq!(answers.check(1, 1, &Answer::from(u64::MAX)), Status::Pass);
+        assert_eq!(
+            answers.check(1, 1, &Answer::from(u64::MAX - 1)),
+            Status::Fail(Answer::Unsigned(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Answers::parse("[day01]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("[dayone]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\nfirst = 1").is_err());
+        assert!(Answers::parse("day01 = 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, &Answer::Int(7)), Status::Pass);
+        assert_eq!(
+            answers.check(1, 2, &Answer::Int(6)),
+            Status::Fail(Answer::Int(5))
+        );
+        assert_eq!(answers.check(3, 1, &Answer::Int(6)), Status::Missing);
+        assert_eq!(
+            answers.check(13, 2, &Answer::from("#####  \n#...#\n#####")),
+            Status::Pass
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use std::{collections::HashMap, hash::Hash, mem};
+
+use crate::{Compass, Direction, Grid};
+
+/// A grid that evolves one generation at a time.
+///
+/// Two buffers are kept around and swapped every generation so stepping
+/// never allocates.
+#[derive(Clone)]
+pub struct Automaton<V> {
+    current: Grid<V>,
+    next: Grid<V>,
+    fired: Grid<bool>,
+    queue: Vec<(usize, usize)>,
+    generation: usize,
+}
+
+/// A cell along with its surroundings, as seen by the rule of an [`Automaton`].
+pub struct Neighbourhood<'a, V> {
+    grid: &'a Grid<V>,
+    x: usize,
+    y: usize,
+}
+
+impl<'a, V> Neighbourhood<'a, V> {
+    pub fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    pub fn value(&self) -> &'a V {
+        &self.grid[(self.x, self.y)]
+    }
+
+    /// The value of the neighbour in a direction, following the boundary of the grid.
+    pub fn get(&self, compass: Compass) -> Option<&'a V> {
+        let delta = compass.delta();
+        self.grid
+            .at(self.x as isize + delta.x, self.y as isize + delta.y)
+    }
+
+    /// The values of the neighbours in the given direction, following the boundary of the grid.
+    pub fn neighbours(&self, direction: Direction) -> impl Iterator<Item = &'a V> {
+        self.grid.neighbour_values(self.x, self.y, direction)
+    }
+}
+
+/// The generations at which an [`Automaton`] started repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cycle {
+    /// The first generation that is repeated.
+    pub start: usize,
+    /// The number of generations before it repeats, which is 1 for a fixed point.
+    pub length: usize,
+}
+
+impl<V> Automaton<V>
+where
+    V: Clone,
+{
+    pub fn new(grid: Grid<V>) -> Self {
+        Self {
+            next: grid.clone(),
+            fired: Grid::new(grid.width(), grid.height()),
+            queue: Vec::new(),
+            current: grid,
+            generation: 0,
+        }
+    }
+}
+
+impl<V> Automaton<V> {
+    pub fn grid(&self) -> &Grid<V> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<V> {
+        self.current
+    }
+
+    /// The number of generations stepped so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation of every cell at once from the current generation.
+    ///
+    /// Returns the number of cells that changed.
+    pub fn step<F>(&mut self, mut rule: F) -> usize
+    where
+        V: PartialEq,
+        F: FnMut(Neighbourhood<'_, V>) -> V,
+    {
+        let grid = &self.current;
+        let mut changed = 0;
+        for ((x, y), value) in self.next.items_mut() {
+            *value = rule(Neighbourhood { grid, x, y });
+            if *value != grid[(x, y)] {
+                changed += 1;
+            }
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Like [`Automaton::step`] but spreads the cells across threads.
+    ///
+    /// Every cell only reads the current generation, so the result is the same
+    /// as stepping on a single thread.
+    #[cfg(feature = "parallel")]
+    pub fn par_step<F>(&mut self, rule: F) -> usize
+    where
+        V: PartialEq + Send + Sync,
+        F: Fn(Neighbourhood<'_, V>) -> V + Sync + Send,
+    {
+        use rayon::prelude::*;
+
+        let grid = &self.current;
+        let changed = self
+            .next
+            .par_items_mut()
+            .map(|((x, y), value)| {
+                *value = rule(Neighbourhood { grid, x, y });
+                *value != grid[(x, y)]
+            })
+            .filter(|&changed| changed)
+            .count();
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Updates every cell in place, where a cell firing updates its neighbours in turn.
+    ///
+    /// `update` is called once on every cell and then again on the neighbours of
+    /// every cell it returns `true` for, until nothing else fires. A cell fires at
+    /// most once, and once it has `update` isn't called on it again. After that
+    /// `settle` is called on every cell that fired.
+    ///
+    /// Returns the number of cells that fired.
+    pub fn cascade<F, S>(&mut self, direction: Direction, mut update: F, mut settle: S) -> usize
+    where
+        F: FnMut(&mut V) -> bool,
+        S: FnMut(&mut V),
+    {
+        for ((x, y), value) in self.current.items_mut() {
+            if update(value) {
+                self.fired[(x, y)] = true;
+                self.queue.push((x, y));
+            }
+        }
+
+        let mut index = 0;
+        while let Some(&(x, y)) = self.queue.get(index) {
+            index += 1;
+            for p in self.current.neighbours(x, y, direction) {
+                if !self.fired[p] && update(&mut self.current[p]) {
+                    self.fired[p] = true;
+                    self.queue.push(p);
+                }
+            }
+        }
+
+        let fired = self.queue.len();
+        for p in self.queue.drain(..) {
+            self.fired[p] = false;
+            settle(&mut self.current[p]);
+        }
+        self.generation += 1;
+        fired
+    }
+
+    /// Runs generations until one doesn't change anything.
+    ///
+    /// `generation` advances the automaton by a generation, usually with
+    /// [`Automaton::step`], and returns the number of cells that changed.
+    /// Returns the number of generations run, including the one that didn't change anything.
+    pub fn until_stable<F>(&mut self, mut generation: F) -> usize
+    where
+        F: FnMut(&mut Self) -> usize,
+    {
+        let mut count = 1;
+        while generation(self) != 0 {
+            count += 1;
+        }
+        count
+    }
+
+    /// Runs generations until a previous state of the grid comes back.
+    ///
+    /// `generation` advances the automaton by a generation, like with [`Automaton::until_stable`].
+    /// Every state is kept around, so this is only suitable for automatons that repeat quickly.
+    pub fn until_cycle<F>(&mut self, mut generation: F) -> Cycle
+    where
+        V: Clone + Eq + Hash,
+        F: FnMut(&mut Self),
+    {
+        let mut seen = HashMap::new();
+        let mut index = 0;
+        loop {
+            if let Some(start) = seen.insert(self.current.as_vec().clone(), index) {
+                return Cycle {
+                    start,
+                    length: index - start,
+                };
+            }
+            generation(self);
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Boundary;
+
+    fn life(n: Neighbourhood<'_, bool>) -> bool {
+        let alive = n.neighbours(Direction::Adjacent).filter(|&&v| v).count();
+        matches!((n.value(), alive), (true, 2) | (_, 3))
+    }
+
+    fn board(s: &str) -> Grid<bool> {
+        let cells: Vec<_> = s
+            .lines()
+            .flat_map(|l| l.chars())
+            .map(|c| c == '#')
+            .collect();
+        let width = s.find('\n').unwrap();
+        let height = cells.len() / width;
+        Grid::with_data(cells, width, height)
+    }
+
+    #[test]
+    fn test_step() {
+        let mut automaton = Automaton::new(board(".....\n..#..\n..#..\n..#..\n....."));
+        assert_eq!(automaton.step(life), 4);
+        assert_eq!(automaton.grid().row(2), &[false, true, true, true, false]);
+        assert_eq!(automaton.generation(), 1);
+
+        let cycle = automaton.until_cycle(|a| {
+            a.step(life);
+        });
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_until_stable() {
+        // a block never changes, and the lone cell dies straight away
+        let grid = board("#.......\n........\n........\n....##..\n....##..\n........");
+        let mut automaton = Automaton::new(grid.with_boundary(Boundary::Wrap));
+        assert_eq!(automaton.until_stable(|a| a.step(life)), 2);
+        assert_eq!(automaton.grid().as_vec().iter().filter(|&&v| v).count(), 4);
+
+        let cycle = automaton.until_cycle(|a| {
+            a.step(life);
+        });
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_step() {
+        let grid = board("........\n..#.....\n...#....\n.###....\n........\n........");
+        let mut serial = Automaton::new(grid.clone().with_boundary(Boundary::Wrap));
+        let mut parallel = Automaton::new(grid.with_boundary(Boundary::Wrap));
+        for _ in 0..20 {
+            assert_eq!(serial.step(life), parallel.par_step(life));
+            assert_eq!(serial.grid().as_vec(), parallel.grid().as_vec());
+        }
+        assert_eq!(parallel.generation(), 20);
+    }
+
+    #[test]
+    fn test_cascade() {
+        let mut automaton = Automaton::new(Grid::single_ascii_number("989\n111\n000"));
+        let fired = automaton.cascade(
+            Direction::Adjacent,
+            |v| {
+                *v += 1;
+                *v > 9
+            },
+            |v| *v = 0,
+        );
+        assert_eq!(fired, 3);
+        assert_eq!(automaton.grid().as_vec(), &vec![0, 0, 0, 4, 5, 4, 1, 1, 1]);
+    }
+}
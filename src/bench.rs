@@ -0,0 +1,308 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+
+use crate::Solution;
+
+/// A phase of a day's solution that gets timed separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Summary statistics over a number of timed runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of the given timings.
+    ///
+    /// Returns [`None`] if there are no timings.
+    pub fn new(timings: &[Duration]) -> Option<Self> {
+        if timings.is_empty() {
+            return None;
+        }
+
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let total: Duration = sorted.iter().sum();
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: total / sorted.len() as u32,
+        })
+    }
+}
+
+/// The timing statistics of a single phase of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+fn time<T, F>(iterations: usize, mut f: F) -> Result<Stats>
+where
+    F: FnMut() -> Result<T>,
+{
+    let mut timings = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let t = Instant::now();
+        black_box(f()?);
+        timings.push(t.elapsed());
+    }
+    Stats::new(&timings).context("at least one iteration is required")
+}
+
+/// Times every phase of a day's solution over the given number of iterations.
+///
+/// The input is parsed once more before timing the parts, so each part is only
+/// timed on the already parsed input.
+pub fn measure<S>(solution: &S, input: &str, iterations: usize) -> Result<Vec<Measurement>>
+where
+    S: Solution,
+{
+    let day = solution.day();
+    let mut result = Vec::with_capacity(3);
+    let stats = time(iterations, || solution.parse(black_box(input)))?;
+    result.push(Measurement {
+        day,
+        phase: Phase::Parse,
+        stats,
+    });
+
+    let parsed = solution.parse(input)?;
+    let stats = time(iterations, || solution.part1(black_box(&parsed)))?;
+    result.push(Measurement {
+        day,
+        phase: Phase::Part1,
+        stats,
+    });
+    if solution.parts() >= 2 {
+        let stats = time(iterations, || solution.part2(black_box(&parsed)))?;
+        result.push(Measurement {
+            day,
+            phase: Phase::Part2,
+            stats,
+        });
+    }
+    Ok(result)
+}
+
+/// Serialises measurements to JSON, with every duration in nanoseconds.
+pub fn to_json(measurements: &[Measurement], iterations: usize) -> String {
+    let results: Vec<Value> = measurements
+        .iter()
+        .map(|m| {
+            json!({
+                "day": m.day,
+                "phase": m.phase.name(),
+                "min_ns": m.stats.min.as_nanos() as u64,
+                "median_ns": m.stats.median.as_nanos() as u64,
+                "mean_ns": m.stats.mean.as_nanos() as u64,
+            })
+        })
+        .collect();
+    let value = json!({
+        "iterations": iterations,
+        "results": results,
+    });
+    serde_json::to_string_pretty(&value).expect("JSON values always serialise")
+}
+
+/// Deserialises measurements written by [`to_json`].
+pub fn from_json(s: &str) -> Result<Vec<Measurement>> {
+    let value: Value = serde_json::from_str(s)?;
+    let results = value["results"]
+        .as_array()
+        .context("missing `results` array")?;
+
+    let duration = |result: &Value, key: &str| -> Result<Duration> {
+        result[key]
+            .as_u64()
+            .map(Duration::from_nanos)
+            .with_context(|| format!("missing `{}`", key))
+    };
+
+    results
+        .iter()
+        .map(|result| {
+            let day = result["day"]
+                .as_u64()
+                .and_then(|day| u8::try_from(day).ok())
+                .context("missing `day`")?;
+            let phase = result["phase"].as_str().context("missing `phase`")?;
+            let phase = match Phase::from_name(phase) {
+                Some(phase) => phase,
+                None => bail!("unknown phase `{}`", phase),
+            };
+            Ok(Measurement {
+                day,
+                phase,
+                stats: Stats {
+                    min: duration(result, "min_ns")?,
+                    median: duration(result, "median_ns")?,
+                    mean: duration(result, "mean_ns")?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// A phase that got slower compared to a previous run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub before: Duration,
+    pub after: Duration,
+    /// How much slower the phase got, e.g. `0.25` for 25% slower.
+    pub change: f64,
+}
+
+/// Compares the median timings of two runs.
+///
+/// Phases whose median got slower by more than `threshold` (e.g. `0.1` for 10%)
+/// are returned. Phases only present in one of the runs are ignored.
+pub fn compare(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    threshold: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|m| {
+            let before = baseline
+                .iter()
+                .find(|b| b.day == m.day && b.phase == m.phase)?
+                .stats
+                .median;
+            let after = m.stats.median;
+            if before.is_zero() {
+                return None;
+            }
+
+            let change = after.as_secs_f64() / before.as_secs_f64() - 1.0;
+            (change > threshold).then_some(Regression {
+                day: m.day,
+                phase: m.phase,
+                before,
+                after,
+                change,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u8, phase: Phase, median: u64) -> Measurement {
+        Measurement {
+            day,
+            phase,
+            stats: Stats {
+                min: Duration::from_micros(median / 2),
+                median: Duration::from_micros(median),
+                mean: Duration::from_micros(median + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let timings: Vec<_> = [5, 1, 3, 2, 4]
+            .iter()
+            .map(|&x| Duration::from_millis(x))
+            .collect();
+        let stats = Stats::new(&timings).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+
+        let timings: Vec<_> = [4, 1, 2, 9]
+            .iter()
+            .map(|&x| Duration::from_millis(x))
+            .collect();
+        let stats = Stats::new(&timings).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+
+        assert!(Stats::new(&[]).is_none());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let measurements = vec![
+            measurement(1, Phase::Parse, 10),
+            measurement(1, Phase::Part1, 200),
+            measurement(25, Phase::Part1, 3000),
+        ];
+        let json = to_json(&measurements, 10);
+        assert_eq!(from_json(&json).unwrap(), measurements);
+        assert!(from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![
+            measurement(1, Phase::Part1, 100),
+            measurement(1, Phase::Part2, 100),
+            measurement(2, Phase::Part1, 100),
+        ];
+        let current = vec![
+            measurement(1, Phase::Part1, 105),
+            measurement(1, Phase::Part2, 150),
+            measurement(3, Phase::Part1, 1000),
+        ];
+        let regressions = compare(&baseline, &current, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, 1);
+        assert_eq!(regressions[0].phase, Phase::Part2);
+        assert!((regressions[0].change - 0.5).abs() < 1e-9);
+    }
+}
@@ -78,7 +78,7 @@ impl<K> Counter<K> {
     ///
     /// If the key is not found then [`None`] is returned.
     #[inline]
-    pub fn get<'a, Q>(&'a self, key: &Q) -> Option<i64>
+    pub fn get<Q>(&self, key: &Q) -> Option<i64>
     where
         K: Eq + Hash + Borrow<Q>,
         Q: ?Sized + Eq + Hash,
@@ -93,7 +93,7 @@ impl<K> Counter<K> {
     ///
     /// Note that this is different from [`HashMap::entry`].
     #[inline]
-    pub fn entry<'a>(&'a mut self, key: K) -> &'a mut i64
+    pub fn entry(&mut self, key: K) -> &mut i64
     where
         K: Eq + Hash + Copy,
     {
@@ -167,9 +167,9 @@ impl<K> Counter<K> {
     ///
     /// Elements are returned in no particular order.
     /// If an element’s count is less than one, it'll be ignored.
-    pub fn elements<'a>(&'a self) -> impl Iterator<Item = &'a K> {
+    pub fn elements(&self) -> impl Iterator<Item = &K> {
         self.iter()
-            .flat_map(|(k, v)| std::iter::repeat(k).take(*v as usize))
+            .flat_map(|(k, v)| std::iter::repeat_n(k, *v as usize))
     }
 }
 
@@ -355,6 +355,33 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<K> serde::Serialize for Counter<K>
+where
+    K: serde::Serialize,
+{
+    /// Serialises as a map from every key to its count.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(&self.data)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K> serde::Deserialize<'de> for Counter<K>
+where
+    K: serde::Deserialize<'de> + Eq + Hash,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        HashMap::deserialize(deserializer).map(|data| Self { data })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,7 +428,7 @@ mod tests {
             &Counter::<char>::from([('a', 3), ('b', 2), ('c', 1)])
         );
         assert!(counter.keys().all(|c| matches!(*c, 'a' | 'b' | 'c')));
-        assert!(counter.values().all(|c| matches!(*c, 1 | 2 | 3)));
+        assert!(counter.values().all(|c| matches!(*c, 1..=3)));
         assert_eq!(counter[&'b'], 2);
         assert_eq!(counter[&'z'], 0);
         assert_eq!(counter.get(&'b'), Some(2));
@@ -434,4 +461,21 @@ mod tests {
         counter.clear();
         assert!(counter.is_empty());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let counter = Counter::from(['N', 'B', 'N', 'C']);
+        let json = serde_json::to_value(&counter).unwrap();
+        assert_eq!(json, serde_json::json!({"N": 2, "B": 1, "C": 1}));
+        let back: Counter<char> = serde_json::from_value(json).unwrap();
+        assert_eq!(back, counter);
+
+        let pairs = Counter::<String>::from([("NN".to_string(), 3), ("NC".to_string(), -1)]);
+        let json = serde_json::to_string(&pairs).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Counter<String>>(&json).unwrap(),
+            pairs
+        );
+    }
 }
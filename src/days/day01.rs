@@ -0,0 +1,33 @@
+use anyhow::Result;
+
+use crate::{parse, Answer, Solution};
+
+pub fn part1(depths: &[i64]) -> Result<i64> {
+    Ok(depths.windows(2).map(|a| (a[1] > a[0]) as i64).sum())
+}
+
+pub fn part2(depths: &[i64]) -> Result<i64> {
+    Ok(depths.windows(4).map(|a| (a[3] > a[0]) as i64).sum())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i64>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
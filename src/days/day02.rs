@@ -0,0 +1,121 @@
+use anyhow::Result;
+use std::str::FromStr;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Default)]
+struct Submarine {
+    position: i32,
+    depth: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Instruction {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, "expected an instruction and a value"))?;
+        let value: i32 = parse::field(s, value)?;
+        match name {
+            "forward" => Ok(Self::Forward(value)),
+            "up" => Ok(Self::Up(value)),
+            "down" => Ok(Self::Down(value)),
+            _ => Err(ParseError::new(name, "unknown instruction")),
+        }
+    }
+}
+
+impl Submarine {
+    fn process(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Forward(v) => self.position += v,
+            Instruction::Up(v) => self.depth -= v,
+            Instruction::Down(v) => self.depth += v,
+        }
+    }
+}
+
+pub fn part1(instructions: &[Instruction]) -> Result<i32> {
+    let mut submarine = Submarine::default();
+    instructions
+        .iter()
+        .for_each(|&instruction| submarine.process(instruction));
+    Ok(submarine.position * submarine.depth)
+}
+
+#[derive(Debug, Default)]
+struct AimedSubmarine {
+    position: i32,
+    depth: i32,
+    aim: i32,
+}
+
+impl AimedSubmarine {
+    fn process(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Forward(v) => {
+                self.position += v;
+                self.depth += self.aim * v;
+            }
+            Instruction::Up(v) => self.aim -= v,
+            Instruction::Down(v) => self.aim += v,
+        }
+    }
+}
+
+pub fn part2(instructions: &[Instruction]) -> Result<i32> {
+    let mut submarine = AimedSubmarine::default();
+    instructions
+        .iter()
+        .for_each(|&instruction| submarine.process(instruction));
+    Ok(submarine.position * submarine.depth)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed() {
+        let error = parse::lines::<Instruction>("forward 5\ndown x\nup 3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, "x");
+
+        let error = parse::lines::<Instruction>("forward 5\nsideways 2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "sideways");
+    }
+}
@@ -1,14 +1,16 @@
 use anyhow::Result;
 
+use crate::{Answer, Solution};
+
 // warning: not good
 
-fn part1(input: &str) -> Result<u64> {
+pub fn part1(lines: &[&str]) -> Result<u64> {
     const BITS: usize = 12;
     let mut epsilon = String::with_capacity(BITS);
     let mut gamma = String::with_capacity(BITS);
     for index in 0..BITS {
         let mut common = [0u64; 2];
-        for line in input.lines() {
+        for line in lines {
             let byte = line.as_bytes()[index];
             common[(byte - b'0') as usize] += 1;
         }
@@ -40,8 +42,8 @@ fn filter_vec(vec: &mut Vec<String>, low: u8, high: u8) {
     }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut oxygen: Vec<String> = input.lines().map(String::from).collect();
+pub fn part2(lines: &[&str]) -> Result<u64> {
+    let mut oxygen: Vec<String> = lines.iter().copied().map(String::from).collect();
     let mut co2 = oxygen.clone();
     filter_vec(&mut oxygen, b'1', b'0');
     filter_vec(&mut co2, b'0', b'1');
@@ -51,9 +53,24 @@ fn part2(input: &str) -> Result<u64> {
     Ok(oxygen * co2)
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("day03_input.txt");
-    println!("{:?}", part1(input)?);
-    println!("{:?}", part2(input)?);
-    Ok(())
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
 }
@@ -0,0 +1,148 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{bail, Result};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Grid, Solution,
+};
+
+#[derive(Clone)]
+struct Cell {
+    value: u32,
+    hit: bool,
+}
+
+impl Cell {
+    fn new(value: u32) -> Self {
+        Self { value, hit: false }
+    }
+}
+
+#[derive(Clone)]
+pub struct Board {
+    grid: Grid<Cell>,
+}
+
+impl Board {
+    const WIDTH: usize = 5;
+
+    fn is_winner(&self) -> bool {
+        self.grid.rows().any(|row| row.iter().all(|c| c.hit))
+            || self.grid.columns().any(|mut column| column.all(|c| c.hit))
+    }
+
+    fn tick(&mut self, value: u32) {
+        if let Some((_, c)) = self.grid.items_mut().find(|(_, c)| c.value == value) {
+            c.hit = true;
+        }
+    }
+
+    fn unmarked_sum(&self) -> u32 {
+        self.grid
+            .items()
+            .filter(|(_, c)| !c.hit)
+            .map(|(_, c)| c.value)
+            .sum()
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s
+            .split_whitespace()
+            .map(|f| parse::field(s, f).map(Cell::new))
+            .collect::<Result<Vec<_>, _>>()?;
+        if data.len() != Self::WIDTH * Self::WIDTH {
+            return Err(ParseError::new(s, "expected a 5x5 board"));
+        }
+        Ok(Self {
+            grid: Grid::with_data(data, Self::WIDTH, Self::WIDTH),
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    let (lot, boards) = parse::two_sections(input)?;
+    let lot = parse::ints(lot)?;
+    let boards = parse::sections(boards)
+        .map(|f| parse::within(input, f))
+        .collect::<Result<_, _>>()?;
+    Ok((lot, boards))
+}
+
+pub fn part1((lot, boards): &(Vec<u32>, Vec<Board>)) -> Result<u32> {
+    let mut boards = boards.clone();
+    for &number in lot {
+        for board in &mut boards {
+            board.tick(number);
+            if board.is_winner() {
+                return Ok(board.unmarked_sum() * number);
+            }
+        }
+    }
+    bail!("unreachable")
+}
+
+pub fn part2((lot, boards): &(Vec<u32>, Vec<Board>)) -> Result<u32> {
+    let mut boards = boards.clone();
+    let count = boards.len();
+    let mut winners = HashSet::new();
+    for &number in lot {
+        for (index, board) in boards.iter_mut().enumerate() {
+            board.tick(number);
+            if board.is_winner() {
+                winners.insert(index);
+                if winners.len() == count {
+                    return Ok(board.unmarked_sum() * number);
+                }
+            }
+        }
+    }
+    bail!("unreachable")
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = (Vec<u32>, Vec<Board>);
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD: &str =
+        " 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+
+    #[test]
+    fn test_malformed() {
+        let input = format!("7,4,9\n\n{}\n\n{}", BOARD, BOARD.replace("18", "1x"));
+        let error = parse_input(&input).err().unwrap();
+        assert_eq!((error.line, error.column), (12, 7));
+        assert_eq!(error.text, "1x");
+
+        let input = format!("7,x,9\n\n{}", BOARD);
+        let error = parse_input(&input).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.text, "x");
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::Result;
+
+use crate::{parse, Answer, Point, Solution, SparseGrid};
+
+struct Graph {
+    grid: SparseGrid<u32>,
+}
+
+impl Graph {
+    fn new() -> Self {
+        Self {
+            grid: SparseGrid::new(0),
+        }
+    }
+
+    fn add(&mut self, p1: Point<isize>, p2: Point<isize>, diagonals: bool) {
+        let (min, max) = if p1 <= p2 { (p1, p2) } else { (p2, p1) };
+        if min.x == max.x {
+            for y in min.y..=max.y {
+                *self.grid.cell_mut(min.x, y) += 1;
+            }
+        } else if min.y == max.y {
+            for x in min.x..=max.x {
+                *self.grid.cell_mut(x, min.y) += 1;
+            }
+        } else if diagonals {
+            let inc = if min.y >= max.y { -1 } else { 1 };
+            let mut y = min.y;
+            for x in min.x..=max.x {
+                *self.grid.cell_mut(x, y) += 1;
+                y += inc;
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.grid.items().filter(|(_, &v)| v >= 2).count()
+    }
+}
+
+type Line = (Point<isize>, Point<isize>);
+
+pub fn part1(lines: &[Line]) -> Result<usize> {
+    let mut graph = Graph::new();
+    for &(p1, p2) in lines {
+        graph.add(p1, p2, false);
+    }
+    Ok(graph.count())
+}
+
+pub fn part2(lines: &[Line]) -> Result<usize> {
+    let mut graph = Graph::new();
+    for &(p1, p2) in lines {
+        graph.add(p1, p2, true);
+    }
+    Ok(graph.count())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<Line>;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::pairs(input, " -> ")?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed() {
+        let error = parse::pairs::<Point<isize>, Point<isize>>("0,9 -> 5,9\n8,0 -> 0,y", " -> ")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.text, "y");
+
+        let error =
+            parse::pairs::<Point<isize>, Point<isize>>("0,9 -> 5,9\n8,0 0,8", " -> ").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "8,0 0,8");
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::{parse, Answer, Solution};
+
+fn solve(input: &[u8], days: usize) -> usize {
+    let mut counts = [0usize; 9];
+    for d in input {
+        counts[*d as usize] += 1;
+    }
+
+    for _ in 0..days {
+        counts.rotate_left(1);
+        counts[6] += counts[8];
+    }
+    counts.iter().sum()
+}
+
+pub fn part1(fish: &[u8]) -> Result<usize> {
+    Ok(solve(fish, 80))
+}
+
+pub fn part2(fish: &[u8]) -> Result<usize> {
+    Ok(solve(fish, 256))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<u8>;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::ints(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
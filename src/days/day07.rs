@@ -0,0 +1,59 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::{parse, Answer, Solution};
+
+pub fn part1(numbers: &[i32]) -> Result<i32> {
+    let mut numbers = numbers.to_vec();
+    numbers.sort();
+    let middle = numbers[numbers.len() / 2];
+    Ok(numbers.iter().map(|f| (f - middle).abs()).sum())
+}
+
+#[inline(always)]
+fn sum(x: i32) -> i32 {
+    (x * (x + 1)) / 2
+}
+
+pub fn part2(numbers: &[i32]) -> Result<i32> {
+    let mut numbers = numbers.to_vec();
+    numbers.sort();
+    let middle = numbers[numbers.len() / 2];
+    let result: i32 = numbers.iter().map(|f| sum((f - middle).abs())).sum();
+
+    let (min, max) = numbers
+        .iter()
+        .copied()
+        .minmax()
+        .into_option()
+        .ok_or(anyhow!("no minmax"))?;
+
+    let rest = (min..=max)
+        .map(|m| numbers.iter().map(|x| sum((x - m).abs())).sum())
+        .min()
+        .ok_or(anyhow!("not found"))?;
+
+    Ok(result.min(rest))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<i32>;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::ints(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
@@ -0,0 +1,229 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+/// The ten unique signal patterns and the four output digits of a display.
+#[derive(Debug)]
+pub struct Entry {
+    patterns: Vec<BTreeSet<u8>>,
+    output: Vec<BTreeSet<u8>>,
+}
+
+/// Parses `count` space separated patterns of segments from `a` to `g`.
+fn segments(s: &str, count: usize) -> Result<Vec<BTreeSet<u8>>, ParseError> {
+    let words: Vec<_> = s.split_ascii_whitespace().collect();
+    if words.len() != count {
+        return Err(ParseError::new(s, format!("expected {} patterns", count)));
+    }
+    words
+        .into_iter()
+        .map(|word| {
+            if word.bytes().all(|b| matches!(b, b'a'..=b'g')) {
+                Ok(word.bytes().collect())
+            } else {
+                Err(ParseError::at(s, word, "expected segments from `a` to `g`"))
+            }
+        })
+        .collect()
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (patterns, output) = s
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::new(s, "expected ` | `"))?;
+        Ok(Self {
+            patterns: segments(patterns, 10).map_err(|e| e.within(s, patterns))?,
+            output: segments(output, 4).map_err(|e| e.within(s, output))?,
+        })
+    }
+}
+
+pub fn part1(entries: &[Entry]) -> Result<usize> {
+    // 1, 4, 7, 8
+    // 2, 4, 3, 7
+    Ok(entries
+        .iter()
+        .flat_map(|entry| &entry.output)
+        .filter(|digit| matches!(digit.len(), 2 | 4 | 3 | 7))
+        .count())
+}
+
+//   0:      1:      2:      3:      4:
+//  aaaa    ....    aaaa    aaaa    ....
+// b    c  .    c  .    c  .    c  b    c
+// b    c  .    c  .    c  .    c  b    c
+//  ....    ....    dddd    dddd    dddd
+// e    f  .    f  e    .  .    f  .    f
+// e    f  .    f  e    .  .    f  .    f
+//  gggg    ....    gggg    gggg    ....
+//
+//   5:      6:      7:      8:      9:
+//  aaaa    aaaa    aaaa    aaaa    aaaa
+// b    .  b    .  .    c  b    c  b    c
+// b    .  b    .  .    c  b    c  b    c
+//  dddd    dddd    ....    dddd    dddd
+// .    f  e    f  .    f  e    f  .    f
+// .    f  e    f  .    f  e    f  .    f
+//  gggg    gggg    ....    gggg    gggg
+
+fn decode_input(input: &[BTreeSet<u8>]) -> Result<Vec<BTreeSet<u8>>> {
+    let mut result = vec![BTreeSet::new(); 10];
+    let mut sixes = Vec::new();
+    let mut fives = Vec::new();
+
+    /*
+        lengths:
+        0 => 6,
+        1 => 2,
+        2 => 5,
+        3 => 5,
+        4 => 4,
+        5 => 5,
+        6 => 6,
+        7 => 3,
+        8 => 7
+        9 => 6
+
+        ordered by length the numbers are 1, 7, 4, 3, 2, 5, 6, 9, 0, 8
+
+        1 => cf
+        7 => acf (a can be found out from that one)
+        3 => acdfg (d and g need to be found out)
+        4 => bcdf (b and d need to be found out)
+        common between 3 and 4 is d, uncommon can be solved
+        that solves ABDG, 5 gives you F, 6 gives you E, 1 gives you C
+        then ABCDEFG are all found
+    */
+
+    // 1, 4, 7, 8 are unique
+    for digits in input.iter().cloned() {
+        match digits.len() {
+            2 => result[1] = digits,
+            3 => result[7] = digits,
+            4 => result[4] = digits,
+            5 => fives.push(digits),
+            6 => sixes.push(digits),
+            7 => result[8] = digits,
+            len => bail!("no digit has {} segments", len),
+        };
+    }
+
+    // differentiate between the different 6s
+    // the different 6 length digits are 0, 6, and 9
+    // between 1 and 6 the only difference is C
+    // between 4 and 0 the only difference is D
+    // otherwise, it must be 9
+    for digit in sixes {
+        if !digit.is_superset(&result[1]) {
+            result[6] = digit;
+        } else if !digit.is_superset(&result[4]) {
+            result[0] = digit;
+        } else {
+            result[9] = digit;
+        }
+    }
+
+    // differentiate between the different 5s
+    // the different 5 lengths are 2, 3, and 5
+    // 6 is only a superset of 5 so it's easy to find
+    // 3 is only a subset of 9 so it's easy to find
+    // the last one has to be 2
+    for digit in fives {
+        if result[6].is_superset(&digit) {
+            result[5] = digit;
+        } else if result[9].is_superset(&digit) {
+            result[3] = digit;
+        } else {
+            result[2] = digit;
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part2(entries: &[Entry]) -> Result<usize> {
+    entries
+        .iter()
+        .map(|entry| {
+            let decoded = decode_input(&entry.patterns)?;
+            entry.output.iter().try_fold(0, |total, digits| {
+                let digit = decoded.iter().position(|d| d == digits).with_context(|| {
+                    let text: String = digits.iter().map(|&b| b as char).collect();
+                    format!("the output `{}` doesn't match any pattern", text)
+                })?;
+                Ok(total * 10 + digit)
+            })
+        })
+        .sum()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Entry>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str =
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc";
+
+    const PATTERNS: &str = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day08.parse(INPUT).unwrap()).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day08.parse(INPUT).unwrap()).unwrap(), 8394 + 9781);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = format!("{} | fdgacbe cefdb cefbgd gcbe\nab cd", PATTERNS);
+        let error = parse::lines::<Entry>(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "ab cd");
+
+        let input = format!("{} | fdgacbe cefdb cxfbgd gcbe", PATTERNS);
+        let error = parse::lines::<Entry>(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 76));
+        assert_eq!(error.text, "cxfbgd");
+
+        // a pattern with a single segment isn't a digit
+        let input = "a ab abc abcd abcde abcdef abcdefg bcdef bcdefg cdefg | ab abc abcd bcd";
+        assert!(part2(&Day08.parse(input).unwrap()).is_err());
+        // `bcd` isn't one of the patterns
+        let input = "ab abc abcd abcde abcdef abcdefg bcdef bcdefg cdefg acdefg | ab abc abcd bcd";
+        assert!(part2(&Day08.parse(input).unwrap()).is_err());
+    }
+}
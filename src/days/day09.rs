@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+use crate::{Answer, Direction, Grid, Solution};
+
+fn low_points(grid: &Grid<u8>) -> impl Iterator<Item = ((usize, usize), &u8)> {
+    grid.items().filter(|&((x, y), value)| {
+        grid.neighbours(x, y, Direction::Cardinal)
+            .all(|p| grid[p] > *value)
+    })
+}
+
+pub fn part1(grid: &Grid<u8>) -> Result<usize> {
+    Ok(low_points(grid).map(|(_, &v)| v as usize + 1).sum())
+}
+
+pub fn part2(grid: &Grid<u8>) -> Result<usize> {
+    let (_, mut basins) = grid.components(Direction::Cardinal, |&v| v != 9);
+    basins.sort_by(|a, b| b.cmp(a));
+    Ok(basins.iter().take(3).product())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Grid<u8>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Grid::try_single_ascii_number(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
@@ -1,5 +1,7 @@
 use anyhow::Result;
 
+use crate::{Answer, Solution};
+
 #[inline]
 fn score_p1(c: char) -> usize {
     match c {
@@ -24,13 +26,10 @@ fn score_p2(c: &char) -> usize {
 
 #[inline]
 fn braces_match(open: char, close: char) -> bool {
-    match (open, close) {
-        ('(', ')') => true,
-        ('<', '>') => true,
-        ('[', ']') => true,
-        ('{', '}') => true,
-        _ => false,
-    }
+    matches!(
+        (open, close),
+        ('(', ')') | ('<', '>') | ('[', ']') | ('{', '}')
+    )
 }
 
 fn illegal_score(l: &str) -> usize {
@@ -41,7 +40,7 @@ fn illegal_score(l: &str) -> usize {
             ')' | ']' | '}' | '>' => {
                 if let Some(top) = stack.pop() {
                     if !braces_match(top, ch) {
-                        return score_p1(ch)
+                        return score_p1(ch);
                     }
                 }
             }
@@ -74,13 +73,14 @@ fn fix_score(l: &str) -> Option<Vec<char>> {
     Some(stack)
 }
 
-fn part1(input: &str) -> Result<usize> {
-    Ok(input.lines().map(illegal_score).sum())
+pub fn part1(lines: &[&str]) -> Result<usize> {
+    Ok(lines.iter().copied().map(illegal_score).sum())
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut scores: Vec<_> = input
-        .lines()
+pub fn part2(lines: &[&str]) -> Result<usize> {
+    let mut scores: Vec<_> = lines
+        .iter()
+        .copied()
         .filter_map(fix_score)
         .map(incomplete_score)
         .collect();
@@ -89,9 +89,24 @@ fn part2(input: &str) -> Result<usize> {
     Ok(scores[total / 2])
 }
 
-fn main() -> Result<()> {
-    let input = include_str!("day10_input.txt").trim_end();
-    println!("{:?}", part1(input)?);
-    println!("{:?}", part2(input)?);
-    Ok(())
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
 }
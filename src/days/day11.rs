@@ -0,0 +1,90 @@
+use anyhow::Result;
+
+use crate::{automaton::Automaton, Answer, Direction, Grid, GridError, Solution};
+
+#[derive(Clone)]
+pub struct Octopuses {
+    automaton: Automaton<u8>,
+    flashes: usize,
+}
+
+impl Octopuses {
+    fn from_input(s: &str) -> Result<Self, GridError> {
+        Ok(Self {
+            automaton: Automaton::new(Grid::try_single_ascii_number(s)?),
+            flashes: 0,
+        })
+    }
+
+    /// Returns whether every octopus flashed.
+    fn step(&mut self) -> bool {
+        let flashed = self.automaton.cascade(
+            Direction::Adjacent,
+            |energy| {
+                *energy += 1;
+                *energy > 9
+            },
+            |energy| *energy = 0,
+        );
+        self.flashes += flashed;
+        flashed == self.automaton.grid().as_vec().len()
+    }
+}
+
+pub fn part1(octopuses: &Octopuses) -> Result<usize> {
+    let mut grid = octopuses.clone();
+    for _ in 0..100 {
+        grid.step();
+    }
+    Ok(grid.flashes)
+}
+
+pub fn part2(octopuses: &Octopuses) -> Result<usize> {
+    let mut grid = octopuses.clone();
+    let mut step = 1;
+    loop {
+        if grid.step() {
+            return Ok(step);
+        }
+        step += 1;
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Octopuses;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Octopuses::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+        assert_eq!(part1(&Day11.parse(input).unwrap()).unwrap(), 1656)
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+        assert_eq!(part2(&Day11.parse(input).unwrap()).unwrap(), 195)
+    }
+}
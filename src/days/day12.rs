@@ -2,8 +2,13 @@ use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
 #[derive(Debug)]
-struct Graph {
+pub struct Graph {
     inner: HashMap<String, Vec<String>>,
 }
 
@@ -44,20 +49,18 @@ impl Graph {
         self.inner.entry(left).or_default().push(right);
     }
 
-    fn from_input(s: &str) -> Graph {
+    fn from_input(s: &str) -> Result<Graph, ParseError> {
         let mut graph = Self::new();
-        s.lines()
-            .filter_map(|s| s.split_once('-'))
-            .for_each(|(begin, end)| {
-                if end != "start" {
-                    graph.add_edge(begin.to_owned(), end.to_owned());
-                }
-                if begin != "start" {
-                    graph.add_edge(end.to_owned(), begin.to_owned());
-                }
-            });
+        for (begin, end) in parse::pairs::<String, String>(s, "-")? {
+            if end != "start" {
+                graph.add_edge(begin.clone(), end.clone());
+            }
+            if begin != "start" {
+                graph.add_edge(end, begin);
+            }
+        }
 
-        graph
+        Ok(graph)
     }
 
     fn paths<'a>(&'a self, second: bool) -> usize {
@@ -84,36 +87,56 @@ impl Graph {
     }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let graphs = Graph::from_input(input);
-    Ok(graphs.paths(true))
+pub fn part1(graph: &Graph) -> Result<usize> {
+    Ok(graph.paths(true))
+}
+
+pub fn part2(graph: &Graph) -> Result<usize> {
+    Ok(graph.paths(false))
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let graphs = Graph::from_input(input);
-    Ok(graphs.paths(false))
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Graph;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Graph::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
+    const INPUT: &str = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(INPUT).unwrap(), 10);
+        assert_eq!(part1(&Day12.parse(INPUT).unwrap()).unwrap(), 10);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(INPUT).unwrap(), 36);
+        assert_eq!(part2(&Day12.parse(INPUT).unwrap()).unwrap(), 36);
     }
-}
 
-fn main() -> Result<()> {
-    let input = include_str!("day12_input.txt").trim_end();
-    println!("{:?}", part1(input)?);
-    println!("{:?}", part2(input)?);
-    Ok(())
+    #[test]
+    fn test_malformed() {
+        let error = Graph::from_input("start-A\nA b\nA-end").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "A b");
+    }
 }
@@ -0,0 +1,219 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    ocr,
+    parse::{self, ParseError},
+    Answer, Point, Render, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Fold {
+    X(u16),
+    Y(u16),
+}
+
+impl Fold {
+    fn apply<'a, T>(&self, points: T) -> HashSet<Point<u16>>
+    where
+        T: Iterator<Item = &'a Point<u16>>,
+    {
+        match self {
+            Fold::X(x) => points
+                .map(|f| {
+                    if f.x < *x {
+                        *f
+                    } else {
+                        Point {
+                            x: 2 * x - f.x,
+                            y: f.y,
+                        }
+                    }
+                })
+                .collect(),
+            Fold::Y(y) => points
+                .map(|f| {
+                    if f.y < *y {
+                        *f
+                    } else {
+                        Point {
+                            x: f.x,
+                            y: 2 * y - f.y,
+                        }
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix("fold along ")
+            .ok_or_else(|| ParseError::new(s, "expected `fold along`"))?;
+        let (coord, value) = rest
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(s, rest, "expected an `=`"))?;
+        match coord {
+            "x" => Ok(Self::X(parse::field(s, value)?)),
+            "y" => Ok(Self::Y(parse::field(s, value)?)),
+            _ => Err(ParseError::at(s, coord, "expected `x` or `y`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Paper {
+    coords: HashSet<Point<u16>>,
+    folds: Vec<Fold>,
+}
+
+impl Paper {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let (coords, folds) = parse::two_sections(s)?;
+        Ok(Self {
+            coords: parse::lines(coords)?.into_iter().collect(),
+            folds: parse::lines(folds).map_err(|e| e.within(s, folds))?,
+        })
+    }
+
+    fn fold(&mut self, all: bool) {
+        let folds = if all {
+            self.folds.iter()
+        } else {
+            self.folds[0..1].iter()
+        };
+        for fold in folds {
+            self.coords = fold.apply(self.coords.iter());
+        }
+    }
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let points = self
+            .coords
+            .iter()
+            .map(|p| Point::new(p.x as isize, p.y as isize));
+        Render::points(points, 'X', ' ').fmt(f)
+    }
+}
+
+pub fn part1(paper: &Paper) -> Result<usize> {
+    let mut paper = paper.clone();
+    paper.fold(false);
+    Ok(paper.coords.len())
+}
+
+pub fn part2(paper: &Paper) -> Result<String> {
+    let mut paper = paper.clone();
+    paper.fold(true);
+    let points = paper
+        .coords
+        .iter()
+        .map(|p| Point::new(p.x as isize, p.y as isize));
+    ocr::read_points(points).with_context(|| format!("unrecognised letters:\n{}", paper))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Paper;
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Paper::new(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn test_part1() {
+        let paper = Paper::new(INPUT).unwrap();
+        println!("{}", &paper);
+        assert_eq!(part1(&paper).unwrap(), 17);
+    }
+
+    #[test]
+    fn test_part2() {
+        // spells `HI` once folded
+        let input = "40,0
+40,1
+40,2
+38,2
+40,3
+40,4
+40,5
+34,0
+32,0
+33,2
+33,4
+33,5
+3,0
+3,1
+1,2
+3,2
+3,3
+3,4
+3,5
+7,0
+7,1
+7,3
+6,5
+8,5
+
+fold along x=20";
+        assert_eq!(part2(&Day13.parse(input).unwrap()).unwrap(), "HI");
+        assert!(part2(&Day13.parse(INPUT).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = Paper::new("6,10\n0,14\n\nfold along y=7\nfold along z=5").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 12));
+        assert_eq!(error.text, "z");
+
+        let error = Paper::new("6,10\n0,1a\n\nfold along y=7").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Counter, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Element(char, char);
+
+impl std::fmt::Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)?;
+        f.write_char(self.1)
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(b), None) => Ok(Self(a, b)),
+            _ => Err(ParseError::new(s, "expected a pair of elements")),
+        }
+    }
+}
+
+impl From<&[u8]> for Element {
+    fn from(x: &[u8]) -> Self {
+        Self(x[0] as char, x[1] as char)
+    }
+}
+
+#[derive(Clone)]
+pub struct Polymer {
+    initial: String,
+    counter: Counter<Element>,
+    rules: HashMap<Element, char>,
+}
+
+impl Polymer {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let (start, rules) = parse::two_sections(s)?;
+        if start.is_empty() {
+            return Err(ParseError::new(s, "expected a polymer template"));
+        }
+        let rules = parse::pairs(rules, " -> ").map_err(|e| e.within(s, rules))?;
+        Ok(Self {
+            initial: start.to_owned(),
+            counter: start.as_bytes().windows(2).map(Element::from).collect(),
+            rules: rules.into_iter().collect(),
+        })
+    }
+
+    fn step(&mut self) -> Result<()> {
+        let mut counter = Counter::new();
+        for (el, &count) in self.counter.iter() {
+            let middle = *self
+                .rules
+                .get(el)
+                .with_context(|| format!("no insertion rule for `{:?}`", el))?;
+            *counter.entry(Element(el.0, middle)) += count;
+            *counter.entry(Element(middle, el.1)) += count;
+        }
+        self.counter = counter;
+        Ok(())
+    }
+
+    fn apply(&mut self, count: usize) -> Result<Counter<char>> {
+        for _ in 0..count {
+            self.step()?;
+        }
+
+        let mut result: Counter<char> = self
+            .counter
+            .iter()
+            .map(|(el, count)| (el.0, *count))
+            .collect();
+        let last = self.initial.as_bytes().last().copied().unwrap() as char;
+        *result.entry(last) += 1;
+        Ok(result)
+    }
+}
+
+fn solve(polymer: &Polymer, count: usize) -> Result<i64> {
+    let mut polymer = polymer.clone();
+    let counts = polymer.apply(count)?;
+    let top = counts.top().unwrap();
+    let bottom = counts.bottom().unwrap();
+    Ok(top.1 - bottom.1)
+}
+
+pub fn part1(polymer: &Polymer) -> Result<i64> {
+    solve(polymer, 10)
+}
+
+pub fn part2(polymer: &Polymer) -> Result<i64> {
+    solve(polymer, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Polymer;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Polymer::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day14.parse(INPUT).unwrap()).unwrap(), 1588);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day14.parse(INPUT).unwrap()).unwrap(), 2188189693529);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = Polymer::from_input("NNCB\n\nCH -> B\nHHH -> N")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.text, "HHH");
+
+        let error = Polymer::from_input("NNCB\n\nCH -> B\nHH -> NC")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(error.text, "NC");
+
+        assert!(Polymer::from_input("\n\nCH -> B").is_err());
+        let polymer = Polymer::from_input("NNCB\n\nCH -> B").ok().unwrap();
+        assert!(part1(&polymer).is_err());
+    }
+}
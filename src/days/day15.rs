@@ -0,0 +1,68 @@
+use anyhow::{Context, Result};
+
+use crate::{search, Answer, Direction, Grid, Solution};
+
+fn shortest_path(grid: &Grid<u8>) -> Option<u16> {
+    let goal = (grid.width().checked_sub(1)?, grid.height().checked_sub(1)?);
+    search::grid_path(grid, (0, 0), goal, Direction::Cardinal, |_, &risk| {
+        Some(risk as u16)
+    })
+    .map(|path| path.cost)
+}
+
+pub fn part1(grid: &Grid<u8>) -> Result<u16> {
+    shortest_path(grid).context("no path found")
+}
+
+pub fn part2(grid: &Grid<u8>) -> Result<u16> {
+    let grid = grid.tile(5, 5, |(tx, ty), &risk| {
+        (risk + tx as u8 + ty as u8 - 1) % 9 + 1
+    });
+    shortest_path(&grid).context("no path found")
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Grid<u8>;
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Grid::try_single_ascii_number(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day15.parse(INPUT).unwrap()).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day15.parse(INPUT).unwrap()).unwrap(), 315);
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::with_data(Vec::new(), 0, 0);
+        assert!(part1(&grid).is_err());
+        assert!(part2(&grid).is_err());
+    }
+}
@@ -0,0 +1,333 @@
+use anyhow::Result;
+
+use crate::{parse::ParseError, Answer, Solution};
+
+#[inline]
+fn to_binary_chars(c: char) -> Option<&'static str> {
+    Some(match c {
+        '0' => "0000",
+        '1' => "0001",
+        '2' => "0010",
+        '3' => "0011",
+        '4' => "0100",
+        '5' => "0101",
+        '6' => "0110",
+        '7' => "0111",
+        '8' => "1000",
+        '9' => "1001",
+        'A' => "1010",
+        'B' => "1011",
+        'C' => "1100",
+        'D' => "1101",
+        'E' => "1110",
+        'F' => "1111",
+        _ => return None,
+    })
+}
+
+/// The bit a packet couldn't be read at and what went wrong.
+type BitError = (usize, &'static str);
+
+/// Reads `len` bits starting at bit `start` as a number.
+fn read(bits: &str, start: usize, len: usize) -> Result<usize, BitError> {
+    bits.get(start..start + len)
+        .and_then(|b| usize::from_str_radix(b, 2).ok())
+        .ok_or((start, "unexpected end of the transmission"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum PacketKind {
+    Literal(u64),
+    Sum(Vec<Packet>),
+    Product(Vec<Packet>),
+    Minimum(Vec<Packet>),
+    Maximum(Vec<Packet>),
+    GreaterThan(Vec<Packet>),
+    LessThan(Vec<Packet>),
+    EqualTo(Vec<Packet>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Packet {
+    version: u8,
+    kind: PacketKind,
+}
+
+impl Packet {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let mut bits = String::with_capacity(s.len() * 4);
+        for (i, c) in s.char_indices() {
+            let digit = to_binary_chars(c).ok_or_else(|| {
+                ParseError::at(s, &s[i..i + c.len_utf8()], "expected a hexadecimal digit")
+            })?;
+            bits.push_str(digit);
+        }
+        let (packet, _) = Self::parse(&bits, 0).map_err(|(bit, message)| {
+            // every hexadecimal digit is four bits
+            let column = (bit / 4).min(s.len());
+            ParseError::at(s, &s[column..(column + 1).min(s.len())], message)
+        })?;
+        Ok(packet)
+    }
+
+    /// Reads the sub-packets of an operator starting at bit `start`, returning
+    /// them along with the bit right after them.
+    fn parse_multiple(bits: &str, start: usize) -> Result<(Vec<Self>, usize), BitError> {
+        let length_type = read(bits, start, 1)?;
+        let mut current = start + 1;
+        let mut packets = Vec::new();
+        if length_type == 0 {
+            let length = read(bits, current, 15)?;
+            current += 15;
+            let expected = current + length;
+            while current < expected {
+                let (packet, next) = Self::parse(bits, current)?;
+                packets.push(packet);
+                current = next;
+            }
+            if current != expected {
+                return Err((start, "the sub-packets don't fit in their length"));
+            }
+        } else {
+            let count = read(bits, current, 11)?;
+            current += 11;
+            while packets.len() != count {
+                let (packet, next) = Self::parse(bits, current)?;
+                packets.push(packet);
+                current = next;
+            }
+        }
+        Ok((packets, current))
+    }
+
+    /// Reads a packet starting at bit `start`, returning it along with the bit
+    /// right after it.
+    fn parse(bits: &str, start: usize) -> Result<(Self, usize), BitError> {
+        // Read the version header
+        let version = read(bits, start, 3)? as u8;
+        let t = read(bits, start + 3, 3)?;
+        let mut current = start + 6;
+        if t == 4 {
+            let mut value: u64 = 0;
+            loop {
+                let group = read(bits, current, 5)?;
+                value = value
+                    .checked_mul(16)
+                    .map(|v| v | (group & 0xf) as u64)
+                    .ok_or((current, "the literal value is too big"))?;
+                current += 5;
+                if group & 0x10 == 0 {
+                    break;
+                }
+            }
+            let kind = PacketKind::Literal(value);
+            return Ok((Packet { version, kind }, current));
+        }
+
+        let (packets, next) = Self::parse_multiple(bits, current)?;
+        if packets.is_empty() {
+            return Err((current, "expected at least one sub-packet"));
+        }
+        if t >= 5 && packets.len() != 2 {
+            return Err((current, "expected two sub-packets to compare"));
+        }
+        let kind = match t {
+            0 => PacketKind::Sum(packets),
+            1 => PacketKind::Product(packets),
+            2 => PacketKind::Minimum(packets),
+            3 => PacketKind::Maximum(packets),
+            5 => PacketKind::GreaterThan(packets),
+            6 => PacketKind::LessThan(packets),
+            _ => PacketKind::EqualTo(packets),
+        };
+        Ok((Packet { version, kind }, next))
+    }
+
+    fn version_sum(&self) -> usize {
+        match &self.kind {
+            PacketKind::Literal(_) => self.version as usize,
+            PacketKind::Sum(rest)
+            | PacketKind::Product(rest)
+            | PacketKind::Minimum(rest)
+            | PacketKind::Maximum(rest)
+            | PacketKind::GreaterThan(rest)
+            | PacketKind::LessThan(rest)
+            | PacketKind::EqualTo(rest) => {
+                self.version as usize + rest.iter().map(|p| p.version_sum()).sum::<usize>()
+            }
+        }
+    }
+
+    fn evaluate(&self) -> usize {
+        match &self.kind {
+            PacketKind::Literal(x) => *x as usize,
+            PacketKind::Sum(rest) => rest.iter().map(|p| p.evaluate()).sum::<usize>(),
+            PacketKind::Product(rest) => rest.iter().fold(1, |a, b| a * b.evaluate()),
+            PacketKind::Minimum(rest) => rest.iter().map(|p| p.evaluate()).min().unwrap(),
+            PacketKind::Maximum(rest) => rest.iter().map(|p| p.evaluate()).max().unwrap(),
+            PacketKind::GreaterThan(packets) => {
+                let lhs = &packets[0];
+                let rhs = &packets[1];
+                if lhs.evaluate() > rhs.evaluate() {
+                    1
+                } else {
+                    0
+                }
+            }
+            PacketKind::LessThan(packets) => {
+                let lhs = &packets[0];
+                let rhs = &packets[1];
+                if lhs.evaluate() < rhs.evaluate() {
+                    1
+                } else {
+                    0
+                }
+            }
+            PacketKind::EqualTo(packets) => {
+                let lhs = &packets[0];
+                let rhs = &packets[1];
+                if lhs.evaluate() == rhs.evaluate() {
+                    1
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+pub fn part1(packet: &Packet) -> Result<usize> {
+    Ok(packet.version_sum())
+}
+
+pub fn part2(packet: &Packet) -> Result<usize> {
+    Ok(packet.evaluate())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Packet;
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Packet::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_value() {
+        let input = "D2FE28";
+        let packet = Packet::from_input(input).unwrap();
+        assert!(matches!(packet.kind, PacketKind::Literal(2021)))
+    }
+
+    #[test]
+    fn test_length_type_zero() {
+        let input = "38006F45291200";
+        let packet = Packet::from_input(input).unwrap();
+        assert_eq!(packet.version, 1);
+        assert!(matches!(packet.kind, PacketKind::LessThan(..)));
+    }
+
+    #[test]
+    fn test_length_type_one() {
+        let input = "EE00D40C823060";
+        let packet = Packet::from_input(input).unwrap();
+        assert_eq!(packet.version, 7);
+        assert!(matches!(packet.kind, PacketKind::Maximum(..)));
+        if let PacketKind::Maximum(v) = &packet.kind {
+            assert_eq!(v.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&Packet::from_input("8A004A801A8002F478").unwrap()).unwrap(),
+            16
+        );
+        assert_eq!(
+            part1(&Packet::from_input("620080001611562C8802118E34").unwrap()).unwrap(),
+            12
+        );
+        assert_eq!(
+            part1(&Packet::from_input("C0015000016115A2E0802F182340").unwrap()).unwrap(),
+            23
+        );
+        assert_eq!(
+            part1(&Packet::from_input("A0016C880162017C3686B18A3D4780").unwrap()).unwrap(),
+            31
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&Packet::from_input("C200B40A82").unwrap()).unwrap(),
+            3
+        );
+        assert_eq!(
+            part2(&Packet::from_input("04005AC33890").unwrap()).unwrap(),
+            54
+        );
+        assert_eq!(
+            part2(&Packet::from_input("880086C3E88112").unwrap()).unwrap(),
+            7
+        );
+        assert_eq!(
+            part2(&Packet::from_input("CE00C43D881120").unwrap()).unwrap(),
+            9
+        );
+        assert_eq!(
+            part2(&Packet::from_input("D8005AC2A8F0").unwrap()).unwrap(),
+            1
+        );
+        assert_eq!(
+            part2(&Packet::from_input("F600BC2D8F").unwrap()).unwrap(),
+            0
+        );
+        assert_eq!(
+            part2(&Packet::from_input("9C005AC2F8F0").unwrap()).unwrap(),
+            0
+        );
+        assert_eq!(
+            part2(&Packet::from_input("9C0141080250320F1802104A08").unwrap()).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = Packet::from_input("8A0G").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.text, "G");
+
+        // a literal that ends after its header
+        let error = Packet::from_input("D2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert_eq!(error.text, "2");
+
+        let error = Packet::from_input("ZZ").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.text, "Z");
+
+        assert!(Packet::from_input("").is_err());
+        assert!(Packet::from_input("38006F").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::ops::RangeInclusive;
+
+use anyhow::Result;
+
+use crate::{parse, Answer, Point, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Probe {
+    position: Point<isize>,
+    velocity: Point<isize>,
+}
+
+impl Probe {
+    fn new(velocity: Point<isize>) -> Self {
+        Self {
+            position: Point::ORIGIN,
+            velocity,
+        }
+    }
+
+    fn step(&mut self) {
+        self.position += self.velocity;
+        self.velocity.x -= self.velocity.x.signum();
+        self.velocity.y -= 1;
+    }
+}
+
+#[derive(Debug)]
+pub struct TargetArea {
+    x: RangeInclusive<isize>,
+    y: RangeInclusive<isize>,
+}
+
+impl TargetArea {
+    fn from_input(s: &str) -> Result<Self> {
+        Ok(TargetArea {
+            x: parse::range(s, "x")?,
+            y: parse::range(s, "y")?,
+        })
+    }
+
+    fn contains(&self, p: &Point<isize>) -> bool {
+        self.x.contains(&p.x) && self.y.contains(&p.y)
+    }
+}
+
+fn simulate(area: &TargetArea, velocity: Point<isize>) -> Option<isize> {
+    let mut probe = Probe::new(velocity);
+    let mut best_y = 0;
+    while probe.position.x < *area.x.end() && probe.position.y >= *area.y.start() {
+        probe.step();
+        best_y = probe.position.y.max(best_y);
+        if area.contains(&probe.position) {
+            return Some(best_y);
+        }
+    }
+    None
+}
+
+pub fn solve(area: &TargetArea) -> Result<(isize, usize)> {
+    let mut possibilities = 0;
+    let mut best_y = 0;
+    for y in *area.y.start()..=area.y.start().abs() {
+        for x in 0..=*area.x.end() {
+            let velocity = Point::new(x, y);
+            if let Some(max) = simulate(area, velocity) {
+                possibilities += 1;
+                best_y = best_y.max(max);
+            }
+        }
+    }
+
+    Ok((best_y, possibilities))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = TargetArea;
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        TargetArea::from_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        solve(input).map(|(y, _)| Answer::from(y))
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        solve(input).map(|(_, count)| Answer::from(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let area = TargetArea::from_input("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(solve(&area).unwrap(), (45, 112));
+    }
+}
@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+
+use crate::{parse::ParseError, Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct SnailfishPair {
+    number: usize,
+    depth: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Snailfish {
+    data: Vec<SnailfishPair>,
+}
+
+impl Snailfish {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let mut depth = 0;
+        let mut data = Vec::new();
+        let mut previous = None;
+        for (i, ch) in s.char_indices() {
+            let text = &s[i..i + ch.len_utf8()];
+            match ch {
+                '[' if depth == 4 => {
+                    return Err(ParseError::at(s, text, "nested more than four pairs deep"))
+                }
+                '[' => depth += 1,
+                ']' if depth == 0 => return Err(ParseError::at(s, text, "unmatched `]`")),
+                ']' => depth -= 1,
+                ',' => {}
+                '0'..='9' if previous.is_some_and(|c: char| c.is_ascii_digit()) => {
+                    return Err(ParseError::at(s, text, "expected a single digit"))
+                }
+                '0'..='9' => data.push(SnailfishPair {
+                    number: (ch as u8 - b'0') as usize,
+                    depth,
+                }),
+                _ => return Err(ParseError::at(s, text, "unexpected character")),
+            }
+            previous = Some(ch);
+        }
+        if depth != 0 {
+            return Err(ParseError::at(s, &s[s.len()..], "unclosed `[`"));
+        }
+        if data.is_empty() {
+            return Err(ParseError::new(s, "expected a snailfish number"));
+        }
+        Ok(Self { data })
+    }
+
+    fn explode(&mut self) -> Option<usize> {
+        let index = self.data.iter().position(|p| p.depth > 4)?;
+
+        if index > 0 {
+            self.data[index - 1].number += self.data[index].number;
+        }
+        if index + 2 < self.data.len() {
+            self.data[index + 2].number += self.data[index + 1].number
+        }
+        let pair = &mut self.data[index];
+        pair.number = 0;
+        pair.depth -= 1;
+        Some(index + 1)
+    }
+
+    fn split(&mut self) -> Option<(usize, SnailfishPair)> {
+        let index = self.data.iter().position(|p| p.number >= 10)?;
+        let pair = &mut self.data[index];
+
+        let half = pair.number as f64 / 2.0;
+        let lower = half.floor() as usize;
+        let upper = half.ceil() as usize;
+
+        pair.number = lower;
+        pair.depth += 1;
+        Some((
+            index + 1,
+            SnailfishPair {
+                number: upper,
+                depth: pair.depth,
+            },
+        ))
+    }
+
+    fn reduce(&mut self) {
+        loop {
+            if let Some(index) = self.explode() {
+                self.data.remove(index);
+            } else if let Some((index, pair)) = self.split() {
+                self.data.insert(index, pair);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn magnitude(mut self) -> usize {
+        while self.data.len() > 1 {
+            let max_depth = self.data.iter().map(|p| p.depth).max().unwrap_or_default();
+            if max_depth == 0 {
+                break;
+            }
+
+            match self.data.iter().position(|p| p.depth == max_depth) {
+                Some(index) => {
+                    self.data[index].number =
+                        3 * self.data[index].number + 2 * self.data[index + 1].number;
+                    self.data[index].depth -= 1;
+                    self.data.remove(index + 1);
+                }
+                None => break,
+            }
+        }
+        self.data[0].number
+    }
+}
+
+impl std::ops::Add<Snailfish> for Snailfish {
+    type Output = Snailfish;
+
+    fn add(mut self, rhs: Snailfish) -> Self::Output {
+        self.data.extend(rhs.data);
+        for pair in self.data.iter_mut() {
+            pair.depth += 1;
+        }
+        self.reduce();
+        self
+    }
+}
+
+pub fn part1(fishes: &[Snailfish]) -> Result<usize> {
+    let fish = fishes
+        .iter()
+        .cloned()
+        .reduce(|a, b| a + b)
+        .context("no snailfish numbers")?;
+    Ok(fish.magnitude())
+}
+
+pub fn part2(fishes: &[Snailfish]) -> Result<usize> {
+    fishes
+        .iter()
+        .permutations(2)
+        .map(|perm| {
+            let x = perm[0].clone() + perm[1].clone();
+            x.magnitude()
+        })
+        .max()
+        .context("not enough snailfish numbers")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Snailfish>;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input
+            .lines()
+            .map(|line| Snailfish::from_input(line).map_err(|e| e.within(input, line)))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&[Snailfish::from_input("[[1,2],[[3,4],5]]").unwrap()]).unwrap(),
+            143
+        );
+        assert_eq!(
+            part1(&[Snailfish::from_input("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()]).unwrap(),
+            1384
+        );
+        assert_eq!(
+            part1(&[Snailfish::from_input("[[[[1,1],[2,2]],[3,3]],[4,4]]").unwrap()]).unwrap(),
+            445
+        );
+        assert_eq!(
+            part1(&[Snailfish::from_input("[[[[3,0],[5,3]],[4,4]],[5,5]]").unwrap()]).unwrap(),
+            791
+        );
+        assert_eq!(
+            part1(&[Snailfish::from_input("[[[[5,0],[7,4]],[5,5]],[6,6]]").unwrap()]).unwrap(),
+            1137
+        );
+        assert_eq!(
+            part1(&[Snailfish::from_input(
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+            )
+            .unwrap()])
+            .unwrap(),
+            3488
+        );
+        assert_eq!(part1(&Day18.parse(INPUT).unwrap()).unwrap(), 4140);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day18.parse(INPUT).unwrap()).unwrap(), 3993);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = Day18.parse("[1,2]\n[1,x]").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "x");
+
+        let error = Snailfish::from_input("[[1,2],3").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        let error = Snailfish::from_input("[1,2]]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert!(Snailfish::from_input("[[[[[1,2],3],4],5],6]").is_err());
+        assert!(Snailfish::from_input("[12,3]").is_err());
+    }
+}
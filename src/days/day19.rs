@@ -4,9 +4,14 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{bail, Error, Result};
+use anyhow::Result;
 use itertools::Itertools;
 
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Vector3 {
     x: i32,
@@ -15,12 +20,15 @@ struct Vector3 {
 }
 
 impl FromStr for Vector3 {
-    type Err = Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let el: Vec<i32> = s.split(',').map(|p| p.parse()).collect::<Result<_, _>>()?;
+        let el: Vec<i32> = s
+            .split(',')
+            .map(|p| parse::field(s, p))
+            .collect::<Result<_, _>>()?;
         if el.len() != 3 {
-            bail!("not enough elements")
+            Err(ParseError::new(s, "expected three coordinates"))
         } else {
             Ok(Self {
                 x: el[0],
@@ -101,20 +109,23 @@ impl Vector3 {
     }
 }
 
-#[derive(Debug)]
-struct Scanner {
+#[derive(Debug, Clone)]
+pub struct Scanner {
     beacons: Vec<Vector3>,
     position: Vector3,
     index: usize,
 }
 
 impl Scanner {
-    fn from_input(index: usize, s: &str) -> Self {
-        Self {
-            beacons: s.lines().skip(1).filter_map(|f| f.parse().ok()).collect(),
+    fn from_input(index: usize, s: &str) -> Result<Self, ParseError> {
+        let (_, beacons) = s
+            .split_once('\n')
+            .ok_or_else(|| ParseError::new(s, "expected a scanner header and beacons"))?;
+        Ok(Self {
+            beacons: parse::lines(beacons).map_err(|e| e.within(s, beacons))?,
             index,
             position: Vector3::default(),
-        }
+        })
     }
 }
 
@@ -165,12 +176,15 @@ fn search(scanners: &mut Vec<Scanner>, scanner: usize, searched: &mut Vec<bool>)
     }
 }
 
-fn solve(input: &str) -> Result<(usize, usize)> {
-    let mut scanners: Vec<_> = input
-        .split("\n\n")
+fn scanners(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    parse::sections(input)
         .enumerate()
-        .map(|(i, s)| Scanner::from_input(i, s))
-        .collect();
+        .map(|(i, s)| Scanner::from_input(i, s).map_err(|e| e.within(input, s)))
+        .collect()
+}
+
+pub fn solve(scanners: &[Scanner]) -> Result<(usize, usize)> {
+    let mut scanners = scanners.to_vec();
 
     let mut searched = vec![false; scanners.len()];
     search(&mut scanners, 0, &mut searched);
@@ -190,11 +204,33 @@ fn solve(input: &str) -> Result<(usize, usize)> {
     Ok((beacons.len(), max as usize))
 }
 
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Scanner>;
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(scanners(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        solve(input).map(|(beacons, _)| Answer::from(beacons))
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        solve(input).map(|(_, distance)| Answer::from(distance))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "--- scanner 0 ---
+    const INPUT: &str = "--- scanner 0 ---
 404,-588,-901
 528,-643,409
 -838,591,734
@@ -333,12 +369,18 @@ mod tests {
 
     #[test]
     fn test() {
-        assert_eq!(solve(INPUT).unwrap(), (79, 3621));
+        assert_eq!(solve(&scanners(INPUT).unwrap()).unwrap(), (79, 3621));
     }
-}
 
-fn main() -> Result<()> {
-    let input = include_str!("day19_input.txt").trim_end();
-    println!("{:?}", solve(input)?);
-    Ok(())
+    #[test]
+    fn test_malformed() {
+        let input = "--- scanner 0 ---\n0,2,1\n4,1,1\n\n--- scanner 1 ---\n-1,-1,1\n-5,x,1";
+        let error = scanners(input).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 4));
+        assert_eq!(error.text, "x");
+
+        let error = scanners("--- scanner 0 ---\n0,2,1\n\n--- scanner 1 ---").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.text, "--- scanner 1 ---");
+    }
 }
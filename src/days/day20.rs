@@ -0,0 +1,149 @@
+use anyhow::Result;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution, SparseGrid,
+};
+
+#[derive(Debug, Clone)]
+pub struct Image {
+    algorithm: Vec<bool>,
+    /// Every pixel outside of the bounds has the background value.
+    pixels: SparseGrid<bool>,
+}
+
+const ADJACENT: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Image {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let (algorithm, image) = parse::two_sections(s)?;
+        if algorithm.len() != 512 {
+            return Err(ParseError::at(
+                s,
+                algorithm,
+                "expected an algorithm of 512 pixels",
+            ));
+        }
+        let algorithm: Vec<bool> = algorithm.chars().map(|ch| ch == '#').collect();
+
+        // mfw gotcha
+        let mut pixels = SparseGrid::new(false);
+        for (y, line) in image.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                pixels.insert(x as isize, y as isize, ch == '#');
+            }
+        }
+        if pixels.is_empty() {
+            return Err(ParseError::at(s, image, "expected at least one pixel"));
+        }
+        Ok(Self { algorithm, pixels })
+    }
+
+    fn binary_at(&self, x: isize, y: isize) -> usize {
+        ADJACENT.iter().fold(0, |acc, (dx, dy)| {
+            acc << 1 | *self.pixels.get(x + dx, y + dy) as usize
+        })
+    }
+
+    fn step(&mut self) {
+        let background = if *self.pixels.background() {
+            self.algorithm[511]
+        } else {
+            self.algorithm[0]
+        };
+        let mut pixels = SparseGrid::new(background);
+        if let Some(bounds) = self.pixels.bounds() {
+            for (x, y) in bounds.grow(1).coordinates() {
+                pixels.insert(x, y, self.algorithm[self.binary_at(x, y)]);
+            }
+        }
+        self.pixels = pixels;
+    }
+
+    fn pixels(&self) -> usize {
+        self.pixels.items().filter(|(_, &lit)| lit).count()
+    }
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(bounds) = self.pixels.bounds() {
+            self.pixels
+                .render(|&lit| if lit { '#' } else { '.' })
+                .crop(bounds.grow(1))
+                .fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(image: &Image) -> Result<usize> {
+    let mut image = image.clone();
+    for _ in 0..2 {
+        image.step();
+    }
+    Ok(image.pixels())
+}
+
+pub fn part2(image: &Image) -> Result<usize> {
+    let mut image = image.clone();
+    for _ in 0..50 {
+        image.step();
+    }
+    Ok(image.pixels())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Image;
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Image::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day20.parse(INPUT).unwrap()).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day20.parse(INPUT).unwrap()).unwrap(), 3351);
+    }
+}
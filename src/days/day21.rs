@@ -1,8 +1,18 @@
 use std::collections::HashMap;
 
-use anyhow::Result;
-
-const INPUT: [u32; 2] = [6, 1];
+use anyhow::{Context, Result};
+
+use crate::{Answer, Solution};
+
+fn starting_positions(input: &str) -> Result<[u32; 2]> {
+    let mut positions = input.lines().map(|line| -> Result<u32> {
+        let (_, position) = line.split_once(": ").context("bad input")?;
+        Ok(position.parse()?)
+    });
+    let p1 = positions.next().context("missing player 1")??;
+    let p2 = positions.next().context("missing player 2")??;
+    Ok([p1, p2])
+}
 
 struct Player {
     position: u32,
@@ -26,9 +36,9 @@ impl Player {
     }
 }
 
-fn part1() -> Result<usize> {
-    let mut p1 = Player::new(INPUT[0]);
-    let mut p2 = Player::new(INPUT[1]);
+pub fn part1(&[p1, p2]: &[u32; 2]) -> Result<usize> {
+    let mut p1 = Player::new(p1);
+    let mut p2 = Player::new(p2);
 
     let mut dice = 0;
 
@@ -70,7 +80,10 @@ fn quantum_dice(
     current_player: usize,
     cache: &mut Cache,
 ) -> Wins {
-    if let Some(wins) = cache.get(&(pos1, pos2, score1, score2, current_player)).copied() {
+    if let Some(wins) = cache
+        .get(&(pos1, pos2, score1, score2, current_player))
+        .copied()
+    {
         return wins;
     }
 
@@ -103,14 +116,50 @@ fn quantum_dice(
     wins
 }
 
-fn part2() -> Option<usize> {
+pub fn part2(&[p1, p2]: &[u32; 2]) -> Result<usize> {
     let mut cache = Cache::new();
-    let winners = quantum_dice(INPUT[0], INPUT[1], 0, 0, 0, &mut cache);
-    winners.iter().max().cloned()
+    let winners = quantum_dice(p1, p2, 0, 0, 0, &mut cache);
+    Ok(winners.iter().max().copied().unwrap_or_default())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = [u32; 2];
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        starting_positions(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
 }
 
-fn main() -> Result<()> {
-    println!("{:?}", part1()?);
-    println!("{:?}", part2().unwrap());
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day21.parse(INPUT).unwrap()).unwrap(), 739785);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&Day21.parse(INPUT).unwrap()).unwrap(),
+            444356092776315
+        );
+    }
 }
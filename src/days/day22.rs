@@ -1,8 +1,11 @@
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
-use once_cell::sync::Lazy;
-use regex::Regex;
+use anyhow::Result;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
 
 /// An inclusive range because RangeInclusive is kind of a pain
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -55,7 +58,7 @@ impl Bounds {
 }
 
 #[derive(Debug)]
-struct Step {
+pub struct Step {
     on: bool,
     bounds: Bounds,
 }
@@ -88,82 +91,97 @@ impl Cuboid {
     }
 }
 
-static REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(on|off) x=(-?\d+)..(-?\d+),y=(-?\d+)..(-?\d+),z=(-?\d+)..(-?\d+)").unwrap()
-});
-
 impl FromStr for Step {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let captures = REGEX.captures(s).context("missing")?;
+        let captures = parse::captures(
+            parse::regex!(r"^(on|off) x=(\S+)\.\.(\S+),y=(\S+)\.\.(\S+),z=(\S+)\.\.(\S+)$"),
+            s,
+        )?;
         let bounds = Bounds {
             x: Range {
-                start: captures[2].parse()?,
-                end: captures[3].parse()?,
+                start: captures.parse(2)?,
+                end: captures.parse(3)?,
             },
             y: Range {
-                start: captures[4].parse()?,
-                end: captures[5].parse()?,
+                start: captures.parse(4)?,
+                end: captures.parse(5)?,
             },
             z: Range {
-                start: captures[6].parse()?,
-                end: captures[7].parse()?,
+                start: captures.parse(6)?,
+                end: captures.parse(7)?,
             },
         };
         Ok(Self {
-            on: captures[1].as_bytes() == b"on",
+            on: captures.str(1)? == "on",
             bounds,
         })
     }
 }
 
-fn part1(input: &str) -> Result<isize> {
+pub fn part1(steps: &[Step]) -> Result<isize> {
     let mut cuboids: Vec<Cuboid> = Vec::new();
     let range = Range {
         start: -50,
         end: 50,
     };
 
-    input
-        .lines()
-        .filter_map(|s| s.parse::<Step>().ok())
-        .for_each(|s| {
-            if let Some(bounds) = s.bounds.intersect(&Bounds::new(range)) {
-                for cube in &mut cuboids {
-                    cube.remove(&bounds);
-                }
-                if s.on {
-                    cuboids.push(Cuboid::new(bounds));
-                }
+    steps.iter().for_each(|s| {
+        if let Some(bounds) = s.bounds.intersect(&Bounds::new(range)) {
+            for cube in &mut cuboids {
+                cube.remove(&bounds);
             }
-        });
+            if s.on {
+                cuboids.push(Cuboid::new(bounds));
+            }
+        }
+    });
 
     Ok(cuboids.iter().map(|c| c.volume()).sum())
 }
 
-fn part2(input: &str) -> Result<isize> {
+pub fn part2(steps: &[Step]) -> Result<isize> {
     let mut cuboids: Vec<Cuboid> = Vec::new();
-    input
-        .lines()
-        .filter_map(|s| s.parse::<Step>().ok())
-        .for_each(|s| {
-            for cube in &mut cuboids {
-                cube.remove(&s.bounds);
-            }
-            if s.on {
-                cuboids.push(Cuboid::new(s.bounds));
-            }
-        });
+    steps.iter().for_each(|s| {
+        for cube in &mut cuboids {
+            cube.remove(&s.bounds);
+        }
+        if s.on {
+            cuboids.push(Cuboid::new(s.bounds.clone()));
+        }
+    });
 
     Ok(cuboids.iter().map(|c| c.volume()).sum())
 }
 
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<Step>;
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "on x=-20..26,y=-36..17,z=-47..7
+    const INPUT: &str = "on x=-20..26,y=-36..17,z=-47..7
 on x=-20..33,y=-21..23,z=-26..28
 on x=-22..28,y=-29..23,z=-38..16
 on x=-46..7,y=-6..46,z=-50..-1
@@ -189,12 +207,12 @@ on x=967..23432,y=45373..81175,z=27513..53682";
     #[test]
     fn test_simple() {
         let input = "on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13\noff x=9..11,y=9..11,z=9..11\non x=10..10,y=10..10,z=10..10";
-        assert_eq!(part1(input).unwrap(), 39);
+        assert_eq!(part1(&Day22.parse(input).unwrap()).unwrap(), 39);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(INPUT).unwrap(), 590784);
+        assert_eq!(part1(&Day22.parse(INPUT).unwrap()).unwrap(), 590784);
     }
 
     #[test]
@@ -260,21 +278,22 @@ off x=-70369..-16548,y=22648..78696,z=-1892..86821
 on x=-53470..21291,y=-120233..-33476,z=-44150..38147
 off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
 
-        assert_eq!(part2(input).unwrap(), 2758514936282235);
+        assert_eq!(
+            part2(&Day22.parse(input).unwrap()).unwrap(),
+            2758514936282235
+        );
     }
-}
 
-fn main() -> Result<()> {
-    let input = include_str!("day22_input.txt").trim_end();
-
-    let t = std::time::Instant::now();
-    println!("{:?}", part1(input)?);
-    let c = std::time::Instant::now().duration_since(t);
-    println!("Took {:.2}ms", c.as_secs_f64() * 1000.0);
+    #[test]
+    fn test_malformed() {
+        let input = "on x=10..12,y=10..12,z=10..12\non x=11..13,y=1a..13,z=11..13";
+        let error = parse::lines::<Step>(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(error.text, "1a");
 
-    let t = std::time::Instant::now();
-    println!("{:?}", part2(input)?);
-    let c = std::time::Instant::now().duration_since(t);
-    println!("Took {:.2}ms", c.as_secs_f64() * 1000.0);
-    Ok(())
+        let input = "on x=10..12,y=10..12,z=10..12\ntoggle x=11..13,y=11..13,z=11..13";
+        let error = parse::lines::<Step>(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "toggle x=11..13,y=11..13,z=11..13");
+    }
 }
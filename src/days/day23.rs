@@ -0,0 +1,231 @@
+use anyhow::{Context, Result};
+
+use crate::{parse::ParseError, search, Answer, Solution};
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+const MAX_DEPTH: usize = 4;
+const EMPTY: u8 = u8::MAX;
+
+/// The rows that get unfolded from the diagram in part 2.
+const FOLDED: [[u8; ROOMS]; 2] = [[3, 2, 1, 0], [3, 1, 0, 2]];
+
+#[inline]
+fn energy(amphipod: u8) -> usize {
+    10usize.pow(amphipod as u32)
+}
+
+/// The hallway position right outside of a room.
+#[inline]
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+#[inline]
+fn is_door(x: usize) -> bool {
+    matches!(x, 2 | 4 | 6 | 8)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Burrow {
+    hallway: [u8; HALLWAY],
+    /// Each room from the top slot down.
+    ///
+    /// Rooms shallower than [`MAX_DEPTH`] are padded at the bottom with
+    /// amphipods that are already home so they never move.
+    rooms: [[u8; MAX_DEPTH]; ROOMS],
+}
+
+impl Burrow {
+    fn from_input(s: &str) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+        for line in s.lines().skip(2) {
+            let row: Vec<u8> = line
+                .bytes()
+                .filter(|b| b.is_ascii_uppercase())
+                .map(|b| b - b'A')
+                .collect();
+            if row.is_empty() {
+                continue;
+            }
+            if row.len() != ROOMS || row.iter().any(|&a| a as usize >= ROOMS) {
+                return Err(ParseError::at(s, line, "expected a row of four amphipods"));
+            }
+            rows.push([row[0], row[1], row[2], row[3]]);
+        }
+
+        if rows.len() != 2 {
+            return Err(ParseError::new(s, "expected two rows of amphipods"));
+        }
+        for amphipod in 0..ROOMS as u8 {
+            let count = rows.iter().flatten().filter(|&&a| a == amphipod).count();
+            if count != rows.len() {
+                let name = (b'A' + amphipod) as char;
+                return Err(ParseError::new(
+                    s,
+                    format!("expected two `{}` amphipods but found {}", name, count),
+                ));
+            }
+        }
+        let mut rooms = [[0; MAX_DEPTH]; ROOMS];
+        for (room, slots) in rooms.iter_mut().enumerate() {
+            for (depth, slot) in slots.iter_mut().enumerate() {
+                *slot = rows.get(depth).map_or(room as u8, |row| row[room]);
+            }
+        }
+        Ok(Self {
+            hallway: [EMPTY; HALLWAY],
+            rooms,
+        })
+    }
+
+    /// Inserts the folded rows between the two rows of the diagram.
+    fn unfold(mut self) -> Self {
+        for (room, slots) in self.rooms.iter_mut().enumerate() {
+            slots[FOLDED.len() + 1] = slots[1];
+            for (depth, row) in FOLDED.iter().enumerate() {
+                slots[depth + 1] = row[room];
+            }
+        }
+        self
+    }
+
+    fn is_done(&self) -> bool {
+        self.rooms
+            .iter()
+            .enumerate()
+            .all(|(room, slots)| slots.iter().all(|&a| a as usize == room))
+    }
+
+    /// Whether a room only has amphipods that belong in it.
+    fn is_settled(&self, room: usize) -> bool {
+        self.rooms[room]
+            .iter()
+            .all(|&a| a == EMPTY || a as usize == room)
+    }
+
+    /// Whether the hallway is empty between two positions, excluding `from`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let range = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[range].iter().all(|&a| a == EMPTY)
+    }
+
+    fn moves(&self) -> Vec<(Self, usize)> {
+        // moving an amphipod home is always the best move so it's done on its own
+        for (x, &amphipod) in self.hallway.iter().enumerate() {
+            if amphipod == EMPTY {
+                continue;
+            }
+            let room = amphipod as usize;
+            if !self.is_settled(room) || !self.is_clear(x, door(room)) {
+                continue;
+            }
+            let depth = self.rooms[room]
+                .iter()
+                .rposition(|&a| a == EMPTY)
+                .expect("settled rooms with a free hallway have space");
+            let mut next = *self;
+            next.hallway[x] = EMPTY;
+            next.rooms[room][depth] = amphipod;
+            let steps = x.abs_diff(door(room)) + depth + 1;
+            return vec![(next, steps * energy(amphipod))];
+        }
+
+        let mut result = Vec::new();
+        for room in 0..ROOMS {
+            if self.is_settled(room) {
+                continue;
+            }
+            let depth = match self.rooms[room].iter().position(|&a| a != EMPTY) {
+                Some(depth) => depth,
+                None => continue,
+            };
+            let amphipod = self.rooms[room][depth];
+            for x in (0..HALLWAY).filter(|&x| !is_door(x)) {
+                if !self.is_clear(door(room), x) {
+                    continue;
+                }
+                let mut next = *self;
+                next.rooms[room][depth] = EMPTY;
+                next.hallway[x] = amphipod;
+                let steps = x.abs_diff(door(room)) + depth + 1;
+                result.push((next, steps * energy(amphipod)));
+            }
+        }
+        result
+    }
+}
+
+fn solve(burrow: Burrow) -> Result<usize> {
+    search::dijkstra(burrow, Burrow::moves, Burrow::is_done)
+        .map(|path| path.cost)
+        .context("the amphipods can't be organised")
+}
+
+pub fn part1(burrow: &Burrow) -> Result<usize> {
+    solve(*burrow)
+}
+
+pub fn part2(burrow: &Burrow) -> Result<usize> {
+    solve(burrow.unfold())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Burrow;
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Burrow::from_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day23.parse(INPUT).unwrap()).unwrap(), 12521);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day23.parse(INPUT).unwrap()).unwrap(), 44169);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = "#############\n#...........#\n###A#A#B#C###\n  #A#A#D#D#\n  #########";
+        let error = Burrow::from_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected two `A` amphipods but found 4");
+
+        let input = "#############\n#...........#\n###A#E#B#C###\n  #A#B#D#D#\n  #########";
+        let error = Burrow::from_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "###A#E#B#C###");
+    }
+}
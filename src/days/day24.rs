@@ -0,0 +1,303 @@
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Instruction {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+}
+
+fn register(line: &str, s: &str) -> Result<usize, ParseError> {
+    match s {
+        "w" => Ok(0),
+        "x" => Ok(1),
+        "y" => Ok(2),
+        "z" => Ok(3),
+        _ => Err(ParseError::at(line, s, "expected a register")),
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_ascii_whitespace();
+        let name = words
+            .next()
+            .ok_or_else(|| ParseError::new(s, "expected an instruction"))?;
+        let a = register(s, words.next().unwrap_or_default())?;
+        if name == "inp" {
+            return Ok(Self::Inp(a));
+        }
+
+        let b = words
+            .next()
+            .ok_or_else(|| ParseError::new(s, "expected a second operand"))?;
+        let b = match register(s, b) {
+            Ok(r) => Operand::Register(r),
+            Err(_) => Operand::Value(parse::field(s, b)?),
+        };
+        match name {
+            "add" => Ok(Self::Add(a, b)),
+            "mul" => Ok(Self::Mul(a, b)),
+            "div" => Ok(Self::Div(a, b)),
+            "mod" => Ok(Self::Mod(a, b)),
+            "eql" => Ok(Self::Eql(a, b)),
+            _ => Err(ParseError::at(s, name, "unknown instruction")),
+        }
+    }
+}
+
+/// The arithmetic logic unit of the submarine.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Value(v) => v,
+        }
+    }
+
+    /// Runs a program, reading `inp` values from the input in order.
+    fn run(&mut self, program: &[Instruction], input: &[i64]) -> Result<()> {
+        let mut input = input.iter();
+        for &instruction in program {
+            match instruction {
+                Instruction::Inp(a) => {
+                    self.registers[a] = *input.next().context("ran out of input")?;
+                }
+                Instruction::Add(a, b) => self.registers[a] += self.value(b),
+                Instruction::Mul(a, b) => self.registers[a] *= self.value(b),
+                Instruction::Div(a, b) => {
+                    let b = self.value(b);
+                    ensure!(b != 0, "division by zero");
+                    self.registers[a] /= b;
+                }
+                Instruction::Mod(a, b) => {
+                    let b = self.value(b);
+                    ensure!(self.registers[a] >= 0 && b > 0, "invalid modulo");
+                    self.registers[a] %= b;
+                }
+                Instruction::Eql(a, b) => {
+                    self.registers[a] = (self.registers[a] == self.value(b)) as i64
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The parameters of one of the 14 nearly identical blocks in MONAD.
+///
+/// Each block treats `z` as a base 26 stack. Blocks that divide `z` by 1 push
+/// `w + offset` and blocks that divide by 26 pop a value and only avoid pushing
+/// again if it's equal to `w - check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    pops: bool,
+    check: i64,
+    offset: i64,
+}
+
+impl Block {
+    const LENGTH: usize = 18;
+
+    /// The source of a block with the given parameters.
+    fn source(div: i64, check: i64, offset: i64) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\n\
+             add y {}\nmul y x\nadd z y",
+            div, check, offset
+        )
+    }
+
+    fn from_instructions(block: &[Instruction]) -> Result<Self> {
+        use Instruction::{Add, Div};
+        use Operand::Value;
+
+        let (div, check, offset) = match (block.get(4), block.get(5), block.get(15)) {
+            (Some(Div(3, Value(div))), Some(Add(1, Value(check))), Some(Add(2, Value(offset)))) => {
+                (*div, *check, *offset)
+            }
+            _ => bail!("block does not match the expected MONAD shape"),
+        };
+        let expected: Vec<Instruction> = parse::lines(&Self::source(div, check, offset))?;
+        ensure!(
+            block == expected,
+            "block does not match the expected MONAD shape"
+        );
+
+        let pops = match div {
+            1 => false,
+            26 => true,
+            _ => bail!("unexpected division by {}", div),
+        };
+        Ok(Self {
+            pops,
+            check,
+            offset,
+        })
+    }
+}
+
+/// Finds the largest and smallest model numbers that MONAD accepts.
+///
+/// Every pushing block is paired with a popping block and the digits of
+/// each pair have to differ by a fixed amount for `z` to end up at 0.
+fn analyse(program: &[Instruction]) -> Result<(i64, i64)> {
+    ensure!(
+        !program.is_empty() && program.len().is_multiple_of(Block::LENGTH),
+        "program is not made of MONAD blocks"
+    );
+    let blocks = program
+        .chunks(Block::LENGTH)
+        .map(Block::from_instructions)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut largest = vec![0; blocks.len()];
+    let mut smallest = vec![0; blocks.len()];
+    let mut stack = Vec::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if !block.pops {
+            stack.push((index, block.offset));
+            continue;
+        }
+
+        let (other, offset) = stack.pop().context("block pops from an empty stack")?;
+        // digit[index] = digit[other] + diff
+        let diff = offset + block.check;
+        ensure!(
+            diff.abs() < 9,
+            "no digits satisfy blocks {} and {}",
+            other,
+            index
+        );
+        largest[other] = 9.min(9 - diff);
+        largest[index] = largest[other] + diff;
+        smallest[other] = 1.max(1 - diff);
+        smallest[index] = smallest[other] + diff;
+    }
+    ensure!(
+        stack.is_empty(),
+        "not every pushing block has a popping block"
+    );
+
+    let number = |digits: &[i64]| digits.iter().fold(0, |acc, d| acc * 10 + d);
+    Ok((number(&largest), number(&smallest)))
+}
+
+/// Checks that MONAD accepts a model number by running it.
+fn validate(program: &[Instruction], number: i64) -> Result<i64> {
+    let digits: Vec<i64> = number
+        .to_string()
+        .bytes()
+        .map(|b| (b - b'0') as i64)
+        .collect();
+    let mut alu = Alu::default();
+    alu.run(program, &digits)?;
+    ensure!(alu.registers[3] == 0, "MONAD rejected {}", number);
+    Ok(number)
+}
+
+pub fn part1(program: &[Instruction]) -> Result<i64> {
+    let (largest, _) = analyse(program)?;
+    validate(program, largest)
+}
+
+pub fn part2(program: &[Instruction]) -> Result<i64> {
+    let (_, smallest) = analyse(program)?;
+    validate(program, smallest)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &str, input: &[i64]) -> [i64; 4] {
+        let program: Vec<Instruction> = parse::lines(program).unwrap();
+        let mut alu = Alu::default();
+        alu.run(&program, input).unwrap();
+        alu.registers
+    }
+
+    fn monad(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .map(|&(div, check, offset)| Block::source(div, check, offset))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_alu() {
+        assert_eq!(run("inp x\nmul x -1", &[5])[1], -5);
+        assert_eq!(run("inp z\ninp x\nmul z 3\neql z x", &[2, 6])[3], 1);
+        assert_eq!(run("inp z\ninp x\nmul z 3\neql z x", &[2, 7])[3], 0);
+
+        let binary = "inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2";
+        assert_eq!(run(binary, &[11]), [1, 0, 1, 1]);
+        assert!(parse::lines::<Instruction>("inp q").is_err());
+    }
+
+    #[test]
+    fn test_analyse() {
+        let input = monad(&[(1, 12, 5), (1, 14, 1), (26, -3, 8), (26, -4, 2)]);
+        let program: Vec<Instruction> = parse::lines(&input).unwrap();
+        assert_eq!(part1(&program).unwrap(), 8979);
+        assert_eq!(part2(&program).unwrap(), 1312);
+        assert!(validate(&program, 8978).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use crate::{
+    automaton::{Automaton, Neighbourhood},
+    Answer, Boundary, Compass, FromCell, Grid, GridError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Cell {
+    /// A sea cucumber of the herd facing the given direction.
+    Herd(Compass),
+    Empty,
+}
+
+impl FromCell for Cell {
+    fn from_cell(c: char) -> Option<Self> {
+        match c {
+            '>' | 'v' => Compass::from_cell(c).map(Cell::Herd),
+            '.' => Some(Cell::Empty),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Herd(Compass::East) => f.write_char('>'),
+            Cell::Herd(_) => f.write_char('v'),
+            Cell::Empty => f.write_char('.'),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    automaton: Automaton<Cell>,
+}
+
+impl Map {
+    fn from_input(s: &str) -> Result<Self, GridError> {
+        let grid = Grid::try_from_cells(s)?.with_boundary(Boundary::Wrap);
+        Ok(Self {
+            automaton: Automaton::new(grid),
+        })
+    }
+
+    /// The next state of a cell once every sea cucumber of a herd that can move has moved.
+    fn move_herd(cell: Neighbourhood<'_, Cell>, facing: Compass) -> Cell {
+        let herd = Cell::Herd(facing);
+        match *cell.value() {
+            Cell::Empty if cell.get(facing.opposite()) == Some(&herd) => herd,
+            value if value == herd && cell.get(facing) == Some(&Cell::Empty) => Cell::Empty,
+            value => value,
+        }
+    }
+
+    /// Moves the east-facing herd and then the south-facing herd, returning how many cells changed.
+    #[cfg(not(feature = "parallel"))]
+    fn generation(automaton: &mut Automaton<Cell>) -> usize {
+        automaton.step(|cell| Self::move_herd(cell, Compass::East))
+            + automaton.step(|cell| Self::move_herd(cell, Compass::South))
+    }
+
+    #[cfg(feature = "parallel")]
+    fn generation(automaton: &mut Automaton<Cell>) -> usize {
+        automaton.par_step(|cell| Self::move_herd(cell, Compass::East))
+            + automaton.par_step(|cell| Self::move_herd(cell, Compass::South))
+    }
+
+    /// Returns the number of steps until no sea cucumbers move.
+    fn settle(&mut self) -> usize {
+        self.automaton.until_stable(Self::generation)
+    }
+}
+
+pub fn part1(map: &Map) -> Result<usize> {
+    Ok(map.clone().settle())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Map;
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Map::from_input(input)?)
+    }
+
+    fn parts(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Map::from_input(INPUT).unwrap()).unwrap(), 58);
+    }
+
+    #[test]
+    fn test_part1_display() {
+        let mut map = Map::from_input(INPUT).unwrap();
+        println!("{}", map.automaton.grid());
+        assert!(Map::generation(&mut map.automaton) > 0);
+        println!("{}", map.automaton.grid());
+    }
+}
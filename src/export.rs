@@ -0,0 +1,300 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    iter,
+    path::{Path, PathBuf},
+};
+
+use crate::Grid;
+
+/// A colour as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+    pub const GREEN: Rgb = Rgb(0, 255, 0);
+    pub const BLUE: Rgb = Rgb(0, 0, 255);
+
+    pub fn grey(shade: u8) -> Self {
+        Rgb(shade, shade, shade)
+    }
+
+    /// The perceived brightness of the colour.
+    pub fn luma(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+    }
+}
+
+/// Every pixel of the grid, with each cell drawn as a `scale` by `scale` square.
+fn pixels<V, T, F>(grid: &Grid<V>, scale: usize, pixel: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(&V) -> T,
+{
+    let mut pixels = Vec::with_capacity(grid.width() * grid.height() * scale * scale);
+    for row in grid.rows() {
+        let start = pixels.len();
+        for value in row {
+            let p = pixel(value);
+            pixels.extend(iter::repeat_n(p, scale));
+        }
+        let end = pixels.len();
+        for _ in 1..scale {
+            pixels.extend_from_within(start..end);
+        }
+    }
+    pixels
+}
+
+/// Writes the grid as a binary PPM (P6) image, with each cell `scale` pixels wide.
+pub fn write_ppm<V, W, F>(grid: &Grid<V>, scale: usize, colour: F, mut writer: W) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&V) -> Rgb,
+{
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+    let bytes: Vec<_> = pixels(grid, scale, colour)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    writer.write_all(&bytes)
+}
+
+/// Writes the grid as a binary PGM (P5) image, with each cell `scale` pixels wide.
+pub fn write_pgm<V, W, F>(grid: &Grid<V>, scale: usize, shade: F, mut writer: W) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&V) -> u8,
+{
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    write!(writer, "P5\n{} {}\n255\n", width, height)?;
+    writer.write_all(&pixels(grid, scale, shade))
+}
+
+/// Writes the grid as an SVG image, with each cell `scale` units wide.
+///
+/// Runs of cells with the same colour in a row are drawn as a single rectangle.
+pub fn write_svg<V, W, F>(grid: &Grid<V>, scale: usize, colour: F, mut writer: W) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&V) -> Rgb,
+{
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    writeln!(
+        writer,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+        w = width,
+        h = height
+    )?;
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let fill = colour(&row[x]);
+            let run = row[x..].iter().take_while(|v| colour(v) == fill).count();
+            let Rgb(r, g, b) = fill;
+            writeln!(
+                writer,
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#{:02x}{:02x}{:02x}"/>"##,
+                x * scale,
+                y * scale,
+                run * scale,
+                scale,
+                r,
+                g,
+                b
+            )?;
+            x += run;
+        }
+    }
+    writeln!(writer, "</svg>")
+}
+
+/// Writes the grid as a PNG image, with each cell `scale` pixels wide.
+#[cfg(feature = "png")]
+pub fn write_png<V, W, F>(grid: &Grid<V>, scale: usize, colour: F, writer: W) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(&V) -> Rgb,
+{
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let bytes: Vec<_> = pixels(grid, scale, colour)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&bytes).map_err(io::Error::other)?;
+    writer.finish().map_err(io::Error::other)
+}
+
+/// An image format that grids can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Ppm,
+    /// Greyscale, using the [`Rgb::luma`] of every colour.
+    Pgm,
+    Svg,
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+            Format::Svg => "svg",
+            #[cfg(feature = "png")]
+            Format::Png => "png",
+        }
+    }
+
+    /// Writes the grid in this format, with each cell `scale` pixels wide.
+    pub fn write<V, W, F>(
+        self,
+        grid: &Grid<V>,
+        scale: usize,
+        colour: F,
+        writer: W,
+    ) -> io::Result<()>
+    where
+        W: Write,
+        F: Fn(&V) -> Rgb,
+    {
+        match self {
+            Format::Ppm => write_ppm(grid, scale, colour, writer),
+            Format::Pgm => write_pgm(grid, scale, |v| colour(v).luma(), writer),
+            Format::Svg => write_svg(grid, scale, colour, writer),
+            #[cfg(feature = "png")]
+            Format::Png => write_png(grid, scale, colour, writer),
+        }
+    }
+}
+
+/// Writes a series of grids to numbered files, e.g. to animate the steps of an
+/// [`Automaton`](crate::automaton::Automaton).
+///
+/// Files are named `frame_0000.ppm`, `frame_0001.ppm` and so on.
+pub struct Frames {
+    directory: PathBuf,
+    format: Format,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Writes frames into `directory`, creating it if needed.
+    pub fn new(directory: impl AsRef<Path>, format: Format) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+            format,
+            scale: 1,
+            count: 0,
+        })
+    }
+
+    /// Draws each cell as a `scale` by `scale` square.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The number of frames written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Writes the grid as the next frame, returning the path of the file.
+    pub fn write<V, F>(&mut self, grid: &Grid<V>, colour: F) -> io::Result<PathBuf>
+    where
+        F: Fn(&V) -> Rgb,
+    {
+        let name = format!("frame_{:04}.{}", self.count, self.format.extension());
+        let path = self.directory.join(name);
+        let mut writer = BufWriter::new(File::create(&path)?);
+        self.format.write(grid, self.scale, colour, &mut writer)?;
+        writer.flush()?;
+        self.count += 1;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::single_ascii_number("10\n02")
+    }
+
+    fn colour(v: &u8) -> Rgb {
+        [Rgb::BLACK, Rgb::WHITE, Rgb::RED][*v as usize]
+    }
+
+    #[test]
+    fn test_ppm_pgm() {
+        let mut ppm = Vec::new();
+        write_ppm(&grid(), 1, colour, &mut ppm).unwrap();
+        assert_eq!(
+            ppm,
+            b"P6\n2 2\n255\n\xff\xff\xff\0\0\0\0\0\0\xff\0\0".to_vec()
+        );
+
+        let mut pgm = Vec::new();
+        write_pgm(&grid(), 2, |v| v * 100, &mut pgm).unwrap();
+        assert_eq!(
+            pgm,
+            b"P5\n4 4\n255\n\x64\x64\0\0\x64\x64\0\0\0\0\xc8\xc8\0\0\xc8\xc8".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let grid = Grid::single_ascii_number("1122\n0000");
+        let mut svg = Vec::new();
+        write_svg(&grid, 10, colour, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(r#"width="40" height="20""#));
+        assert!(svg.contains(r##"<rect x="0" y="0" width="20" height="10" fill="#ffffff"/>"##));
+        assert!(svg.contains(r##"<rect x="20" y="0" width="20" height="10" fill="#ff0000"/>"##));
+        assert!(svg.contains(r##"<rect x="0" y="10" width="40" height="10" fill="#000000"/>"##));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_frames() {
+        let directory = std::env::temp_dir().join("aoc2021_test_frames");
+        let mut frames = Frames::new(&directory, Format::Pgm).unwrap().scale(3);
+        let mut grid = grid();
+        for _ in 0..3 {
+            frames.write(&grid, colour).unwrap();
+            grid.as_vec_mut().rotate_left(1);
+        }
+        assert_eq!(frames.count(), 3);
+        let last = fs::read(directory.join("frame_0002.pgm")).unwrap();
+        assert!(last.starts_with(b"P5\n6 6\n255\n"));
+        assert_eq!(last.len(), 11 + 36);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let mut bytes = Vec::new();
+        write_png(&grid(), 2, colour, &mut bytes).unwrap();
+        let mut reader = png::Decoder::new(bytes.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (4, 4));
+        assert_eq!(&pixels[..6], &[255, 255, 255, 255, 255, 255]);
+        assert_eq!(&pixels[pixels.len() - 3..], &[255, 0, 0]);
+    }
+}
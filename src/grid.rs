@@ -1,17 +1,57 @@
 use std::{
     fmt::{Debug, Display, Write},
     hash::Hash,
+    iter::{StepBy, Take},
     ops::{Index, IndexMut},
 };
 
+mod bits;
+mod compass;
+mod error;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod point;
+mod region;
+mod render;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sparse;
+mod transform;
+mod view;
+
+pub use bits::BitGrid;
+pub use compass::{Compass, WithCompass};
+pub use error::GridError;
+pub use point::Point;
+pub use render::Render;
+pub use sparse::{Bounds, SparseGrid};
+pub use view::{GridView, GridViewMut};
+
 /// Boilerplate for grid-related problems
+#[derive(Clone)]
 pub struct Grid<V> {
     data: Vec<V>,
     width: usize,
     height: usize,
+    boundary: Boundary<V>,
 }
 
-const CARDINAL: [(isize, isize); 4] = [(0, 1), (0, -1), (-1, 0), (1, 0)];
+/// What happens when going past the edges of a grid.
+///
+/// This is used by [`Grid::neighbours`], [`Grid::step`] and [`Grid::at`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Boundary<V> {
+    /// Positions outside of the grid don't exist.
+    #[default]
+    Clip,
+    /// Positions wrap around to the other side, like a torus.
+    Wrap,
+    /// Positions outside of the grid don't exist, but have the given value when read.
+    Default(V),
+}
+
+/// In the same order as [`Compass::CARDINAL`].
+const CARDINAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
 
 const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
 
@@ -47,6 +87,13 @@ impl Direction {
             Direction::Adjacent => ADJACENT.iter(),
         }
     }
+
+    /// The compass directions that make up this direction, in the order neighbours are returned.
+    pub fn compass(self) -> impl Iterator<Item = Compass> {
+        self.as_iter().map(|&(dx, dy)| {
+            Compass::from_delta(Point::new(dx, dy)).expect("offsets are single steps")
+        })
+    }
 }
 
 impl<V> Grid<V> {
@@ -55,12 +102,72 @@ impl<V> Grid<V> {
             data,
             width,
             height,
+            boundary: Boundary::Clip,
+        }
+    }
+
+    /// Like [`Grid::with_data`] but checks that there's a value for every cell.
+    pub fn try_with_data(data: Vec<V>, width: usize, height: usize) -> Result<Self, GridError> {
+        if width.checked_mul(height) != Some(data.len()) {
+            return Err(GridError::Length {
+                width,
+                height,
+                found: data.len(),
+            });
+        }
+        Ok(Self::with_data(data, width, height))
+    }
+
+    /// Builds a grid out of lines of characters, converting each one with `cell`.
+    ///
+    /// Every row must have the same length and a single trailing newline is allowed.
+    fn parse<F>(s: &str, mut cell: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> Option<V>,
+    {
+        let s = s.strip_suffix('\n').unwrap_or(s);
+        let mut data = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+        if s.is_empty() {
+            return Ok(Self::with_data(data, 0, 0));
+        }
+
+        for (y, line) in s.split('\n').enumerate() {
+            let row = y + 1;
+            if line.ends_with('\r') {
+                return Err(GridError::CarriageReturn { row });
+            }
+            let start = data.len();
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(GridError::InvalidCell {
+                    row,
+                    column: x + 1,
+                    cell: c,
+                })?;
+                data.push(value);
+            }
+
+            let found = data.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::Ragged {
+                        row,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
         }
+        Ok(Self::with_data(data, width.unwrap_or_default(), height))
     }
 
     #[inline]
     const fn index(&self, x: usize, y: usize) -> usize {
-        (y * self.width as usize) + x
+        (y * self.width) + x
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&V> {
@@ -90,16 +197,88 @@ impl<V> Grid<V> {
         self.height
     }
 
+    /// The neighbours of a cell in the given direction.
+    ///
+    /// Neighbours outside of the grid are skipped unless the boundary wraps.
     pub fn neighbours(&self, x: usize, y: usize, direction: Direction) -> Directional {
         Directional {
             x: x as isize,
             y: y as isize,
             width: self.width as isize,
             height: self.height as isize,
+            wrap: matches!(self.boundary, Boundary::Wrap),
             iter: direction.as_iter(),
         }
     }
 
+    /// The neighbours of a point in the given direction, like [`Grid::neighbours`].
+    pub fn point_neighbours(
+        &self,
+        point: Point<usize>,
+        direction: Direction,
+    ) -> impl Iterator<Item = Point<usize>> {
+        self.neighbours(point.x, point.y, direction)
+            .map(Point::from)
+    }
+
+    /// The values of the neighbours of a cell in the given direction, following the boundary.
+    pub fn neighbour_values(
+        &self,
+        x: usize,
+        y: usize,
+        direction: Direction,
+    ) -> impl Iterator<Item = &V> {
+        direction
+            .as_iter()
+            .filter_map(move |(dx, dy)| self.at(x as isize + dx, y as isize + dy))
+    }
+
+    pub fn boundary(&self) -> &Boundary<V> {
+        &self.boundary
+    }
+
+    pub fn set_boundary(&mut self, boundary: Boundary<V>) {
+        self.boundary = boundary;
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<V>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Wraps a position into the grid if the boundary wraps.
+    ///
+    /// Returns [`None`] if the position is outside of the grid otherwise.
+    fn resolve(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        match self.boundary {
+            Boundary::Wrap => Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)),
+            _ if x < 0 || y < 0 || x >= width || y >= height => None,
+            _ => Some((x as usize, y as usize)),
+        }
+    }
+
+    /// Moves from a cell by the given offset, following the boundary.
+    ///
+    /// Returns [`None`] if the new position is outside of the grid and the boundary doesn't wrap.
+    pub fn step(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        self.resolve(x as isize + dx, y as isize + dy)
+    }
+
+    /// Reads the value at a possibly out of bounds position, following the boundary.
+    pub fn at(&self, x: isize, y: isize) -> Option<&V> {
+        match self.resolve(x, y) {
+            Some(p) => Some(&self[p]),
+            None => match &self.boundary {
+                Boundary::Default(value) => Some(value),
+                _ => None,
+            },
+        }
+    }
+
     pub fn coordinates(&self) -> Coordinates {
         Coordinates::new(self.width, self.height)
     }
@@ -125,6 +304,72 @@ impl<V> Grid<V> {
             iter: self.data.iter_mut(),
         }
     }
+
+    /// Every cell along with its position, like [`Grid::items`].
+    pub fn point_items(&self) -> impl Iterator<Item = (Point<usize>, &V)> {
+        self.items().map(|(p, value)| (Point::from(p), value))
+    }
+
+    /// Returns the cells of a row.
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[V] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        let start = self.index(0, y);
+        &self.data[start..start + self.width]
+    }
+
+    /// Returns the cells of a row.
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> &mut [V] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        let start = self.index(0, y);
+        &mut self.data[start..start + self.width]
+    }
+
+    /// Returns the cells of a column from top to bottom.
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, x: usize) -> Column<'_, V> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        Column::new(self, x, 0, self.height)
+    }
+
+    /// Returns every row from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[V]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Returns every row from top to bottom.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [V]> {
+        self.data.chunks_mut(self.width.max(1)).take(self.height)
+    }
+
+    /// Returns every column from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, V>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Borrows a rectangular window of the grid.
+    ///
+    /// Returns [`None`] if the window doesn't fit inside of the grid.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<GridView<'_, V>> {
+        GridView::new(self, x, y, width, height)
+    }
+
+    /// Mutably borrows a rectangular window of the grid.
+    ///
+    /// Returns [`None`] if the window doesn't fit inside of the grid.
+    pub fn view_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<GridViewMut<'_, V>> {
+        GridViewMut::new(self, x, y, width, height)
+    }
 }
 
 impl<V> Grid<V>
@@ -132,11 +377,7 @@ where
     V: Clone + Default,
 {
     pub fn new(width: usize, height: usize) -> Self {
-        Self {
-            data: vec![Default::default(); width * height],
-            width,
-            height,
-        }
+        Self::with_data(vec![Default::default(); width * height], width, height)
     }
 }
 
@@ -155,6 +396,21 @@ impl<V> IndexMut<(usize, usize)> for Grid<V> {
     }
 }
 
+impl<V> Index<Point<usize>> for Grid<V> {
+    type Output = V;
+
+    fn index(&self, index: Point<usize>) -> &Self::Output {
+        &self.data[self.index(index.x, index.y)]
+    }
+}
+
+impl<V> IndexMut<Point<usize>> for Grid<V> {
+    fn index_mut(&mut self, index: Point<usize>) -> &mut Self::Output {
+        let index = self.index(index.x, index.y);
+        &mut self.data[index]
+    }
+}
+
 pub trait FromCell
 where
     Self: Sized,
@@ -167,21 +423,12 @@ where
     V: FromCell,
 {
     pub fn from_cells(s: &str) -> Option<Self> {
-        let data: Option<Vec<V>> = s
-            .lines()
-            .flat_map(|x| x.chars())
-            .map(|c| V::from_cell(c))
-            .collect();
-
-        let data = data?;
-        let height = s.lines().count();
-        let width = s.find('\n').unwrap_or_else(|| data.len() / height);
+        Self::try_from_cells(s).ok()
+    }
 
-        Some(Self {
-            data,
-            width,
-            height,
-        })
+    /// Like [`Grid::from_cells`] but says what's wrong with the input.
+    pub fn try_from_cells(s: &str) -> Result<Self, GridError> {
+        Self::parse(s, V::from_cell)
     }
 }
 
@@ -190,25 +437,80 @@ pub struct Directional {
     y: isize,
     width: isize,
     height: isize,
+    wrap: bool,
     iter: std::slice::Iter<'static, (isize, isize)>,
 }
 
-impl Iterator for Directional {
-    type Item = (usize, usize);
+impl Directional {
+    /// Also yields the direction each neighbour is in.
+    pub fn with_compass(self) -> WithCompass {
+        WithCompass { inner: self }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_with_offset(&mut self) -> Option<((usize, usize), (isize, isize))> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
         loop {
-            let (dx, dy) = self.iter.next()?;
-            let (x, y) = (self.x + dx, self.y + dy);
-            if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            let &(dx, dy) = self.iter.next()?;
+            let (mut x, mut y) = (self.x + dx, self.y + dy);
+            if self.wrap {
+                x = x.rem_euclid(self.width);
+                y = y.rem_euclid(self.height);
+            } else if x < 0 || y < 0 || x >= self.width || y >= self.height {
                 continue;
             }
 
-            break Some((x as usize, y as usize));
+            break Some(((x as usize, y as usize), (dx, dy)));
+        }
+    }
+}
+
+impl Iterator for Directional {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_offset().map(|(p, _)| p)
+    }
+}
+
+/// The cells of a single column of a grid, from top to bottom.
+pub struct Column<'a, V> {
+    iter: Take<StepBy<std::slice::Iter<'a, V>>>,
+}
+
+impl<'a, V> Column<'a, V> {
+    fn new(grid: &'a Grid<V>, x: usize, y: usize, height: usize) -> Self {
+        // a column of a grid without rows starts past the end of the data
+        let start = grid.index(x, y);
+        let data = grid.data.get(start..).unwrap_or_default();
+        Self {
+            iter: data.iter().step_by(grid.width).take(height),
         }
     }
 }
 
+impl<'a, V> Iterator for Column<'a, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, V> DoubleEndedIterator for Column<'a, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Column<'a, V> {}
+
 pub struct Coordinates {
     width: usize,
     height: usize,
@@ -293,10 +595,18 @@ impl Grid<u8> {
             data,
             width,
             height,
+            boundary: Boundary::Clip,
         }
     }
 }
 
+impl Grid<u8> {
+    /// Like [`Grid::single_ascii_number`] but checks that every cell is a digit.
+    pub fn try_single_ascii_number(input: &str) -> Result<Self, GridError> {
+        Self::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
 impl<V> Debug for Grid<V>
 where
     V: Debug,
@@ -306,6 +616,7 @@ where
             .field("data", &self.data)
             .field("width", &self.width)
             .field("height", &self.height)
+            .field("boundary", &self.boundary)
             .finish()
     }
 }
@@ -328,7 +639,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
+    use crate::automaton::Automaton;
 
     #[test]
     fn test_single_ascii() {
@@ -338,6 +649,51 @@ mod tests {
         assert_eq!(grid.height(), 4);
     }
 
+    #[test]
+    fn test_fallible_constructors() {
+        let grid = Grid::try_single_ascii_number("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(Grid::try_single_ascii_number("").unwrap().width(), 0);
+
+        let err = Grid::try_single_ascii_number("123\n45\n789").unwrap_err();
+        assert_eq!(
+            err,
+            GridError::Ragged {
+                row: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+        let err = Grid::try_single_ascii_number("123\n4x6").unwrap_err();
+        assert_eq!(
+            err,
+            GridError::InvalidCell {
+                row: 2,
+                column: 2,
+                cell: 'x'
+            }
+        );
+        assert_eq!(err.to_string(), "row 2, column 2: invalid cell 'x'");
+        let err = Grid::try_single_ascii_number("123\r\n456\r\n").unwrap_err();
+        assert_eq!(err, GridError::CarriageReturn { row: 1 });
+
+        assert!(Grid::try_with_data(vec![1, 2, 3, 4], 2, 2).is_ok());
+        let err = Grid::try_with_data(vec![1, 2, 3], 2, 2).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::Length {
+                width: 2,
+                height: 2,
+                found: 3
+            }
+        );
+        let err = Grid::<u8>::try_with_data(Vec::new(), 1 << 63, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("a {}x2 grid has too many cells, found 0", 1usize << 63)
+        );
+    }
+
     #[test]
     fn test_coordinates() {
         let s = "11\n22";
@@ -372,6 +728,32 @@ mod tests {
         assert_eq!(bottom, vec![(0, 1), (1, 1), (2, 1), (0, 2), (2, 2)]);
     }
 
+    #[test]
+    fn test_boundary() {
+        let mut grid = Grid::single_ascii_number("123\n456\n789");
+        assert_eq!(grid.step(2, 0, 1, 0), None);
+        assert_eq!(grid.step(2, 0, -1, 1), Some((1, 1)));
+        assert_eq!(grid.at(-1, 0), None);
+        assert_eq!(grid.neighbour_values(0, 0, Direction::Cardinal).count(), 2);
+
+        grid.set_boundary(Boundary::Wrap);
+        assert_eq!(grid.step(2, 0, 1, 0), Some((0, 0)));
+        assert_eq!(grid.step(0, 0, -1, -4), Some((2, 2)));
+        assert_eq!(grid.at(-1, 3), Some(&3));
+        let corner: Vec<_> = grid.neighbours(0, 0, Direction::Cardinal).collect();
+        assert_eq!(corner, vec![(0, 2), (1, 0), (0, 1), (2, 0)]);
+        let empty: Grid<u8> = Grid::with_data(Vec::new(), 0, 3).with_boundary(Boundary::Wrap);
+        assert_eq!(empty.neighbours(0, 0, Direction::Adjacent).count(), 0);
+
+        let grid = grid.with_boundary(Boundary::Default(0));
+        assert_eq!(grid.step(2, 0, 1, 0), None);
+        assert_eq!(grid.at(3, 0), Some(&0));
+        assert_eq!(grid.at(1, 1), Some(&5));
+        let sum: u8 = grid.neighbour_values(0, 0, Direction::Adjacent).sum();
+        assert_eq!(sum, 2 + 4 + 5);
+        assert_eq!(grid.neighbour_values(0, 0, Direction::Adjacent).count(), 8);
+    }
+
     #[test]
     fn test_index() {
         let grid = Grid::single_ascii_number("123\n456\n789");
@@ -380,6 +762,28 @@ mod tests {
         assert_eq!(grid.get(1, 1), Some(&5));
     }
 
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::single_ascii_number("123\n456");
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(
+            grid.column(0).rev().copied().collect::<Vec<_>>(),
+            vec![4, 1]
+        );
+
+        let sums: Vec<u8> = grid.rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(sums, vec![6, 15]);
+        let sums: Vec<u8> = grid.columns().map(|column| column.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+
+        grid.row_mut(0).reverse();
+        for row in grid.rows_mut() {
+            row[1] = 0;
+        }
+        assert_eq!(grid.as_vec(), &vec![3, 0, 1, 4, 0, 6]);
+    }
+
     #[test]
     fn test_items() {
         let grid = Grid::single_ascii_number("123\n456\n789");
@@ -387,35 +791,35 @@ mod tests {
         assert_eq!(sum, 45);
     }
 
+    #[test]
+    fn test_points() {
+        let mut grid = Grid::single_ascii_number("123\n456");
+        let p = Point::new(2, 1);
+        assert_eq!(grid[p], 6);
+        grid[p] = 0;
+        assert_eq!(grid[(2, 1)], 0);
+
+        let neighbours: Vec<_> = grid.point_neighbours(p, Direction::Cardinal).collect();
+        assert_eq!(neighbours, vec![Point::new(2, 0), Point::new(1, 1)]);
+        let (first, value) = grid.point_items().nth(4).unwrap();
+        assert_eq!((first, *value), (Point::new(1, 1), 5));
+    }
+
     #[test]
     fn test_day11_part1() {
         let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
-        let mut grid = Grid::single_ascii_number(input);
+        let mut automaton = Automaton::new(Grid::single_ascii_number(input));
         let mut flashes = 0;
 
-        fn flash(grid: &mut Grid<u8>, seen: &mut HashSet<(usize, usize)>, x: usize, y: usize) {
-            if let Some(value) = grid.get_mut(x, y) {
-                if seen.contains(&(x, y)) {
-                    return;
-                }
-
-                *value += 1;
-                if *value > 9 {
-                    *value = 0;
-                    seen.insert((x, y));
-                    for (x, y) in grid.neighbours(x, y, Direction::Adjacent) {
-                        flash(grid, seen, x, y)
-                    }
-                }
-            }
-        }
-
         for _ in 0..100 {
-            let mut seen = HashSet::new();
-            for (x, y) in grid.coordinates() {
-                flash(&mut grid, &mut seen, x, y);
-            }
-            flashes += seen.len();
+            flashes += automaton.cascade(
+                Direction::Adjacent,
+                |value| {
+                    *value += 1;
+                    *value > 9
+                },
+                |value| *value = 0,
+            );
         }
 
         assert_eq!(flashes, 1656);
@@ -0,0 +1,443 @@
+use std::{
+    fmt::{Display, Write},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use super::{Boundary, Coordinates, Direction, Directional, Grid};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans stored as one bit per cell.
+///
+/// Every row starts on a new word, with the cell at `x` stored in bit `x % 64`
+/// of word `x / 64` of the row. The unused bits at the end of a row are always
+/// zero, so rows can be combined and counted a word at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    stride: usize,
+    width: usize,
+    height: usize,
+    boundary: Boundary<bool>,
+}
+
+impl BitGrid {
+    /// A grid where every cell is `false`.
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(BITS);
+        Self {
+            words: vec![0; stride * height],
+            stride,
+            width,
+            height,
+            boundary: Boundary::Clip,
+        }
+    }
+
+    /// Builds a grid out of lines of `#` and `.`, like day 20's image.
+    pub fn from_cells(s: &str) -> Option<Self> {
+        let grid = Grid::parse(s, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .ok()?;
+        Some(Self::from(&grid))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn locate(&self, x: usize, y: usize) -> (usize, u64) {
+        (y * self.stride + x / BITS, 1 << (x % BITS))
+    }
+
+    /// The mask of the bits in the last word of a row that are cells.
+    fn last_mask(&self) -> u64 {
+        match self.width % BITS {
+            0 => !0,
+            rem => (1 << rem) - 1,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if x < self.width && y < self.height {
+            let (index, bit) = self.locate(x, y);
+            Some(self.words[index] & bit != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets a cell, panicking if it's outside of the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside of a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        let (index, bit) = self.locate(x, y);
+        if value {
+            self.words[index] |= bit;
+        } else {
+            self.words[index] &= !bit;
+        }
+    }
+
+    pub fn boundary(&self) -> Boundary<bool> {
+        self.boundary
+    }
+
+    pub fn set_boundary(&mut self, boundary: Boundary<bool>) {
+        self.boundary = boundary;
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<bool>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// The neighbours of a cell in the given direction, like [`Grid::neighbours`].
+    pub fn neighbours(&self, x: usize, y: usize, direction: Direction) -> Directional {
+        Directional {
+            x: x as isize,
+            y: y as isize,
+            width: self.width as isize,
+            height: self.height as isize,
+            wrap: matches!(self.boundary, Boundary::Wrap),
+            iter: direction.as_iter(),
+        }
+    }
+
+    /// The values of the neighbours of a cell in the given direction, following the boundary.
+    pub fn neighbour_values(
+        &self,
+        x: usize,
+        y: usize,
+        direction: Direction,
+    ) -> impl Iterator<Item = bool> + '_ {
+        direction
+            .as_iter()
+            .filter_map(move |(dx, dy)| self.at(x as isize + dx, y as isize + dy))
+    }
+
+    /// Wraps a position into the grid if the boundary wraps, like [`Grid::step`].
+    fn resolve(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        match self.boundary {
+            Boundary::Wrap => Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)),
+            _ if x < 0 || y < 0 || x >= width || y >= height => None,
+            _ => Some((x as usize, y as usize)),
+        }
+    }
+
+    /// Moves from a cell by the given offset, following the boundary.
+    pub fn step(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        self.resolve(x as isize + dx, y as isize + dy)
+    }
+
+    /// Reads the value at a possibly out of bounds position, following the boundary.
+    pub fn at(&self, x: isize, y: isize) -> Option<bool> {
+        match self.resolve(x, y) {
+            Some((x, y)) => self.get(x, y),
+            None => match self.boundary {
+                Boundary::Default(value) => Some(value),
+                _ => None,
+            },
+        }
+    }
+
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.width, self.height)
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = ((usize, usize), bool)> + '_ {
+        self.coordinates().map(move |(x, y)| {
+            let (index, bit) = self.locate(x, y);
+            ((x, y), self.words[index] & bit != 0)
+        })
+    }
+
+    /// The words of a row, with the cell at `x` in bit `x % 64` of word `x / 64`.
+    pub fn row_words(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    /// The number of cells that are `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The number of cells in a row that are `true`.
+    pub fn row_count_ones(&self, y: usize) -> usize {
+        self.row_words(y)
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    /// Moves every cell by the given offset, a word at a time.
+    ///
+    /// The cell at `(x, y)` of the result is the cell at `(x - dx, y - dy)` of
+    /// this grid, following the boundary, and cells outside of the grid are
+    /// `false` unless the boundary has a default.
+    pub fn shift(&self, dx: isize, dy: isize) -> Self {
+        let mut shifted = Self::new(self.width, self.height).with_boundary(self.boundary);
+        if self.width == 0 || self.height == 0 {
+            return shifted;
+        }
+
+        let (width, height) = (self.width as isize, self.height as isize);
+        let wrap = matches!(self.boundary, Boundary::Wrap);
+        let fill = matches!(self.boundary, Boundary::Default(true));
+        let filled = vec![if fill { !0 } else { 0 }; self.stride];
+        for y in 0..self.height {
+            let source = y as isize - dy;
+            let source = if wrap {
+                Some(source.rem_euclid(height))
+            } else {
+                Some(source).filter(|s| (0..height).contains(s))
+            };
+            let row = match source {
+                Some(source) => self.row_words(source as usize),
+                None => &filled,
+            };
+
+            let start = y * self.stride;
+            let target = &mut shifted.words[start..start + self.stride];
+            if wrap {
+                let dx = dx.rem_euclid(width);
+                self.shift_row(row, target, dx, false);
+                let mut rest = vec![0; self.stride];
+                self.shift_row(row, &mut rest, dx - width, false);
+                target.iter_mut().zip(rest).for_each(|(t, r)| *t |= r);
+            } else {
+                self.shift_row(row, target, dx, fill);
+            }
+        }
+        shifted
+    }
+
+    /// Shifts a single row towards higher `x` by `dx`, filling the cells that
+    /// come from outside of the row with `fill`.
+    fn shift_row(&self, row: &[u64], target: &mut [u64], dx: isize, fill: bool) {
+        let last = self.stride - 1;
+        let last_mask = self.last_mask();
+        let outside = if fill { !0 } else { 0 };
+        let word = |k: isize| -> u64 {
+            if k < 0 || k > last as isize {
+                outside
+            } else if k == last as isize {
+                row[last] | (outside & !last_mask)
+            } else {
+                row[k as usize]
+            }
+        };
+
+        let (words, bits) = (dx.unsigned_abs() / BITS, dx.unsigned_abs() % BITS);
+        let (words, bits) = (words as isize, bits as u32);
+        for (j, target) in target.iter_mut().enumerate() {
+            let j = j as isize;
+            *target = if dx >= 0 {
+                let carry = if bits > 0 {
+                    word(j - words - 1) >> (BITS as u32 - bits)
+                } else {
+                    0
+                };
+                (word(j - words) << bits) | carry
+            } else {
+                let carry = if bits > 0 {
+                    word(j + words + 1) << (BITS as u32 - bits)
+                } else {
+                    0
+                };
+                (word(j + words) >> bits) | carry
+            };
+        }
+        target[last] &= last_mask;
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let data = self.items().map(|(_, value)| value).collect();
+        Grid::with_data(data, self.width, self.height).with_boundary(self.boundary)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height()).with_boundary(*grid.boundary());
+        for ((x, y), &value) in grid.items() {
+            if value {
+                bits.set(x, y, true);
+            }
+        }
+        bits
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut result = self.clone();
+        let last_mask = self.last_mask();
+        for row in result.words.chunks_mut(self.stride.max(1)) {
+            row.iter_mut().for_each(|w| *w = !*w);
+            if let Some(last) = row.last_mut() {
+                *last &= last_mask;
+            }
+        }
+        result
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $symbol:tt) => {
+        impl $assign<&BitGrid> for BitGrid {
+            /// Combines every cell with the cell at the same position, panicking if the sizes differ.
+            fn $assign_method(&mut self, rhs: &BitGrid) {
+                assert_eq!(
+                    (self.width, self.height),
+                    (rhs.width, rhs.height),
+                    "grids have different sizes"
+                );
+                for (w, r) in self.words.iter_mut().zip(&rhs.words) {
+                    *w = *w $symbol *r;
+                }
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, rhs: &BitGrid) -> Self::Output {
+                let mut result = self.clone();
+                result.$assign_method(rhs);
+                result
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (index, bit) = self.locate(x, y);
+                f.write_char(if self.words[index] & bit != 0 {
+                    '#'
+                } else {
+                    '.'
+                })?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_shift(bits: &BitGrid, dx: isize, dy: isize) {
+        let shifted = bits.shift(dx, dy);
+        for ((x, y), value) in shifted.items() {
+            let expected = bits.at(x as isize - dx, y as isize - dy).unwrap_or(false);
+            assert_eq!(
+                value, expected,
+                "({}, {}) shifted by ({}, {})",
+                x, y, dx, dy
+            );
+        }
+        // the unused bits at the end of every row stay clear
+        assert_eq!(
+            shifted.count_ones(),
+            shifted.items().filter(|&(_, v)| v).count()
+        );
+    }
+
+    #[test]
+    fn test_cells() {
+        let mut bits = BitGrid::from_cells("#..#.\n.##..\n").unwrap();
+        assert_eq!((bits.width(), bits.height()), (5, 2));
+        assert_eq!(bits.get(3, 0), Some(true));
+        assert_eq!(bits.get(5, 0), None);
+        assert_eq!(bits.count_ones(), 4);
+        assert_eq!(bits.row_words(1), &[0b00110]);
+
+        bits.set(4, 1, true);
+        bits.set(0, 0, false);
+        assert_eq!(bits.to_string(), "...#.\n.##.#\n");
+        assert_eq!(BitGrid::from(&bits.to_grid()), bits);
+        assert_eq!(bits.at(-1, 0), None);
+        assert_eq!(
+            bits.clone().with_boundary(Boundary::Wrap).at(-1, 1),
+            Some(true)
+        );
+
+        let neighbours: Vec<_> = bits.neighbour_values(4, 0, Direction::Adjacent).collect();
+        assert_eq!(neighbours, vec![true, false, true]);
+        assert!(BitGrid::from_cells("#.\n#").is_none());
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = BitGrid::from_cells("##..\n#.#.").unwrap();
+        let b = BitGrid::from_cells("#.#.\n#..#").unwrap();
+        assert_eq!((&a & &b).to_string(), "#...\n#...\n");
+        assert_eq!((&a | &b).to_string(), "###.\n#.##\n");
+        assert_eq!((&a ^ &b).to_string(), ".##.\n..##\n");
+        assert_eq!((!&a).to_string(), "..##\n.#.#\n");
+        assert_eq!((!&a).count_ones(), 4);
+        assert_eq!(a.row_count_ones(0), 2);
+    }
+
+    #[test]
+    fn test_shift() {
+        // wide enough to cross word boundaries
+        let mut bits = BitGrid::new(150, 4);
+        for x in (0..150).filter(|x| x % 3 == 0 || x % 7 == 0) {
+            bits.set(x, x % 4, true);
+        }
+        for boundary in [Boundary::Clip, Boundary::Wrap, Boundary::Default(true)] {
+            let bits = bits.clone().with_boundary(boundary);
+            for (dx, dy) in [
+                (0, 0),
+                (1, 0),
+                (-1, 1),
+                (63, -2),
+                (-64, 0),
+                (65, 3),
+                (-149, 1),
+            ] {
+                check_shift(&bits, dx, dy);
+            }
+        }
+
+        // the east facing herd of day 25 moving in one go
+        let herd = BitGrid::from_cells("##..#\n.#...")
+            .unwrap()
+            .with_boundary(Boundary::Wrap);
+        let blocked = herd.shift(-1, 0);
+        let moving = &herd & &!&blocked;
+        let moved = &(&herd ^ &moving) | &moving.shift(1, 0);
+        assert_eq!(moved.to_string(), "#.#.#\n..#..\n");
+    }
+}
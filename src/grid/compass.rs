@@ -0,0 +1,193 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::{Directional, FromCell, Point};
+use crate::parse::ParseError;
+
+/// A named compass direction, where north is towards `y = 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Every direction clockwise, starting from north.
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    /// The cardinal directions clockwise, starting from north.
+    pub const CARDINAL: [Compass; 4] =
+        [Compass::North, Compass::East, Compass::South, Compass::West];
+
+    /// The offset of a single step in this direction.
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Compass::North => Point::NORTH,
+            Compass::NorthEast => Point::NORTH_EAST,
+            Compass::East => Point::EAST,
+            Compass::SouthEast => Point::SOUTH_EAST,
+            Compass::South => Point::SOUTH,
+            Compass::SouthWest => Point::SOUTH_WEST,
+            Compass::West => Point::WEST,
+            Compass::NorthWest => Point::NORTH_WEST,
+        }
+    }
+
+    /// The direction of a single step offset, if it is one.
+    pub fn from_delta(delta: Point<isize>) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.delta() == delta)
+    }
+
+    /// Rotates clockwise by the given number of eighths of a turn.
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+}
+
+/// Parses `U`, `D`, `L` and `R` or `^`, `v`, `<` and `>`.
+impl FromCell for Compass {
+    fn from_cell(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Compass::North),
+            'D' | 'v' => Some(Compass::South),
+            'L' | '<' => Some(Compass::West),
+            'R' | '>' => Some(Compass::East),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Compass {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Compass::from_cell), chars.next()) {
+            (Some(compass), None) => Ok(compass),
+            _ => Err(ParseError::new(s, "expected one of `UDLR^v<>`")),
+        }
+    }
+}
+
+impl Display for Compass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Compass::North => "N",
+            Compass::NorthEast => "NE",
+            Compass::East => "E",
+            Compass::SouthEast => "SE",
+            Compass::South => "S",
+            Compass::SouthWest => "SW",
+            Compass::West => "W",
+            Compass::NorthWest => "NW",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The neighbours of a cell along with the direction they're in.
+///
+/// Created by [`Directional::with_compass`].
+pub struct WithCompass {
+    pub(super) inner: Directional,
+}
+
+impl Iterator for WithCompass {
+    type Item = ((usize, usize), Compass);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (p, (dx, dy)) = self.inner.next_with_offset()?;
+        let compass = Compass::from_delta(Point::new(dx, dy)).expect("offsets are single steps");
+        Some((p, compass))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Grid};
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Compass::North.turn_right(), Compass::East);
+        assert_eq!(Compass::North.turn_left(), Compass::West);
+        assert_eq!(Compass::SouthWest.turn_right(), Compass::NorthWest);
+        assert_eq!(Compass::East.opposite(), Compass::West);
+        assert_eq!(Compass::NorthEast.opposite(), Compass::SouthWest);
+        for compass in Compass::ALL {
+            assert_eq!(compass.turn_left().turn_right(), compass);
+            assert_eq!(compass.opposite().delta(), -compass.delta());
+            assert_eq!(Compass::from_delta(compass.delta()), Some(compass));
+        }
+        assert!(Compass::CARDINAL.iter().all(|c| c.is_cardinal()));
+        assert_eq!(Compass::from_delta(Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("U".parse::<Compass>().unwrap(), Compass::North);
+        assert_eq!("<".parse::<Compass>().unwrap(), Compass::West);
+        assert!("UD".parse::<Compass>().is_err());
+        assert!("".parse::<Compass>().is_err());
+
+        let grid: Grid<Compass> = Grid::from_cells(">v\n^L").unwrap();
+        assert_eq!(grid[(1, 0)], Compass::South);
+        assert_eq!(grid[(1, 1)], Compass::West);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::single_ascii_number("123\n456\n789");
+        let neighbours: Vec<_> = grid
+            .neighbours(0, 1, Direction::Cardinal)
+            .with_compass()
+            .collect();
+        assert_eq!(
+            neighbours,
+            vec![
+                ((0, 0), Compass::North),
+                ((1, 1), Compass::East),
+                ((0, 2), Compass::South)
+            ]
+        );
+        assert!(Direction::Cardinal.compass().eq(Compass::CARDINAL));
+        assert_eq!(
+            grid.neighbours(1, 1, Direction::Diagonal)
+                .with_compass()
+                .count(),
+            4
+        );
+    }
+}
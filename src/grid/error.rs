@@ -0,0 +1,68 @@
+use std::{error::Error, fmt::Display};
+
+/// An error from building a [`Grid`](super::Grid) out of untrusted input.
+///
+/// Rows and columns start at 1, like [`ParseError`](crate::parse::ParseError).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GridError {
+    /// A row has a different number of cells than the first row.
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character doesn't represent a cell.
+    InvalidCell {
+        row: usize,
+        column: usize,
+        cell: char,
+    },
+    /// A row ends with `\r`, i.e. the input has Windows line endings.
+    CarriageReturn { row: usize },
+    /// The number of cells doesn't match the dimensions.
+    Length {
+        width: usize,
+        height: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {}: expected {} cells but found {}",
+                row, expected, found
+            ),
+            GridError::InvalidCell { row, column, cell } => {
+                write!(f, "row {}, column {}: invalid cell {:?}", row, column, cell)
+            }
+            GridError::CarriageReturn { row } => {
+                write!(f, "row {}: unexpected carriage return (CRLF input)", row)
+            }
+            GridError::Length {
+                width,
+                height,
+                found,
+            } => match width.checked_mul(*height) {
+                Some(cells) => write!(
+                    f,
+                    "expected {} cells for a {}x{} grid but found {}",
+                    cells, width, height, found
+                ),
+                None => write!(
+                    f,
+                    "a {}x{} grid has too many cells, found {}",
+                    width, height, found
+                ),
+            },
+        }
+    }
+}
+
+impl Error for GridError {}
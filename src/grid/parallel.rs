@@ -0,0 +1,67 @@
+use rayon::prelude::*;
+
+use super::Grid;
+
+impl<V> Grid<V>
+where
+    V: Sync,
+{
+    /// Like [`Grid::items`] but spread across threads.
+    pub fn par_items(&self) -> impl IndexedParallelIterator<Item = ((usize, usize), &V)> {
+        let width = self.width;
+        self.data
+            .par_iter()
+            .enumerate()
+            .map(move |(i, value)| ((i % width, i / width), value))
+    }
+
+    /// Builds a new grid of the same size by passing every cell through `f` on
+    /// multiple threads.
+    ///
+    /// The result doesn't depend on how the cells are split between threads.
+    pub fn par_map<U, F>(&self, f: F) -> Grid<U>
+    where
+        U: Send,
+        F: Fn((usize, usize), &V) -> U + Sync + Send,
+    {
+        let data = self.par_items().map(|(p, value)| f(p, value)).collect();
+        Grid::with_data(data, self.width, self.height)
+    }
+}
+
+impl<V> Grid<V>
+where
+    V: Send,
+{
+    /// Like [`Grid::items_mut`] but spread across threads.
+    pub fn par_items_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = ((usize, usize), &mut V)> {
+        let width = self.width;
+        self.data
+            .par_iter_mut()
+            .enumerate()
+            .map(move |(i, value)| ((i % width, i / width), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel() {
+        let mut grid = Grid::single_ascii_number("123\n456");
+        let items: Vec<_> = grid.par_items().map(|(p, &v)| (p, v)).collect();
+        let expected: Vec<_> = grid.items().map(|(p, &v)| (p, v)).collect();
+        assert_eq!(items, expected);
+
+        grid.par_items_mut()
+            .for_each(|((x, y), v)| *v += (x * y) as u8);
+        assert_eq!(grid.as_vec(), &[1, 2, 3, 4, 6, 8]);
+
+        let mapped = grid.par_map(|(x, _), &v| v as usize * 10 + x);
+        assert_eq!((mapped.width(), mapped.height()), (3, 2));
+        assert_eq!(mapped.as_vec(), &[10, 21, 32, 40, 61, 82]);
+    }
+}
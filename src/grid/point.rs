@@ -0,0 +1,181 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::parse::{self, ParseError};
+
+/// A position or offset in 2D space.
+///
+/// `y` grows downwards, matching [`Grid`](super::Grid).
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+macro_rules! impl_distance {
+    ($($t:ty => $d:ty),*) => {
+        $(
+            impl Point<$t> {
+                /// The sum of the distances along each axis.
+                pub fn manhattan(self, other: Self) -> $d {
+                    self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+                }
+
+                /// The largest of the distances along each axis, i.e. the number of
+                /// king moves between the points.
+                pub fn chebyshev(self, other: Self) -> $d {
+                    self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+                }
+            }
+        )*
+    };
+}
+
+impl_distance!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+/// Offsets are always `isize`, like the offsets of [`Grid::step`](super::Grid::step).
+impl Point<isize> {
+    pub const ORIGIN: Self = Self::new(0, 0);
+    pub const NORTH: Self = Self::new(0, -1);
+    pub const SOUTH: Self = Self::new(0, 1);
+    pub const EAST: Self = Self::new(1, 0);
+    pub const WEST: Self = Self::new(-1, 0);
+    pub const NORTH_EAST: Self = Self::new(1, -1);
+    pub const NORTH_WEST: Self = Self::new(-1, -1);
+    pub const SOUTH_EAST: Self = Self::new(1, 1);
+    pub const SOUTH_WEST: Self = Self::new(-1, 1);
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+/// Parses a point written as `x,y`.
+impl<T> FromStr for Point<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .trim()
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(s, "expected a comma"))?;
+        Ok(Self::new(parse::field(s, x)?, parse::field(s, y)?))
+    }
+}
+
+impl<T: Debug> Debug for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?})", &self.x, &self.y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", &self.x, &self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut p = Point::new(3, -2);
+        assert_eq!(p + Point::EAST, Point::new(4, -2));
+        assert_eq!(p - Point::NORTH * 2, Point::new(3, 0));
+        assert_eq!(-p, Point::new(-3, 2));
+        p += Point::SOUTH_WEST;
+        p -= Point::new(1, 1);
+        assert_eq!(p, Point::new(1, -2));
+        assert_eq!(<(isize, isize)>::from(p), (1, -2));
+    }
+
+    #[test]
+    fn test_distance() {
+        let a: Point<isize> = Point::new(-1, 4);
+        assert_eq!(a.manhattan(Point::ORIGIN), 5);
+        assert_eq!(a.chebyshev(Point::ORIGIN), 4);
+        assert_eq!(Point::new(2u16, 7).manhattan(Point::new(5, 1)), 9);
+    }
+
+    #[test]
+    fn test_parse() {
+        let p: Point<u16> = " 6,10".parse().unwrap();
+        assert_eq!(p, Point::new(6, 10));
+        assert_eq!(p.to_string(), "6,10");
+        assert_eq!(format!("{:?}", p), "(6, 10)");
+        assert!("6;10".parse::<Point<u16>>().is_err());
+        assert!("6,x".parse::<Point<u16>>().is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+
+use super::{Direction, Grid};
+
+impl<V> Grid<V> {
+    /// Every cell connected to `start` in the given direction whose value satisfies `predicate`.
+    ///
+    /// Cells are returned in the order they're reached, starting with `start`.
+    /// If `start` doesn't satisfy the predicate then nothing is returned.
+    pub fn flood_fill<F>(
+        &self,
+        start: (usize, usize),
+        direction: Direction,
+        mut predicate: F,
+    ) -> Vec<(usize, usize)>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        let mut seen = Grid::new(self.width, self.height);
+        self.fill_from(start, direction, &mut predicate, &mut seen)
+    }
+
+    fn fill_from<F>(
+        &self,
+        start: (usize, usize),
+        direction: Direction,
+        predicate: &mut F,
+        seen: &mut Grid<bool>,
+    ) -> Vec<(usize, usize)>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        let mut region = Vec::new();
+        match self.get(start.0, start.1) {
+            Some(value) if !seen[start] && predicate(value) => seen[start] = true,
+            _ => return region,
+        }
+
+        let mut queue = VecDeque::from([start]);
+        while let Some((x, y)) = queue.pop_front() {
+            region.push((x, y));
+            for p in self.neighbours(x, y, direction) {
+                if !seen[p] && predicate(&self[p]) {
+                    seen[p] = true;
+                    queue.push_back(p);
+                }
+            }
+        }
+        region
+    }
+
+    /// Labels every group of connected cells whose values satisfy `predicate`.
+    ///
+    /// Returns a grid with the label of each cell, or [`None`] for cells that don't
+    /// satisfy the predicate, along with the size of each component by label.
+    /// Labels are handed out in row-major order of the first cell of each component.
+    pub fn components<F>(
+        &self,
+        direction: Direction,
+        mut predicate: F,
+    ) -> (Grid<Option<usize>>, Vec<usize>)
+    where
+        F: FnMut(&V) -> bool,
+    {
+        let mut labels = Grid::new(self.width, self.height);
+        let mut sizes = Vec::new();
+        let mut seen = Grid::new(self.width, self.height);
+        for start in self.coordinates() {
+            let region = self.fill_from(start, direction, &mut predicate, &mut seen);
+            if region.is_empty() {
+                continue;
+            }
+            for &p in &region {
+                labels[p] = Some(sizes.len());
+            }
+            sizes.push(region.len());
+        }
+        (labels, sizes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::single_ascii_number(INPUT);
+        let basin = grid.flood_fill((0, 0), Direction::Cardinal, |&v| v != 9);
+        assert_eq!(basin, vec![(0, 0), (1, 0), (0, 1)]);
+        assert_eq!(
+            grid.flood_fill((9, 0), Direction::Cardinal, |&v| v != 9)
+                .len(),
+            9
+        );
+        assert!(grid
+            .flood_fill((2, 0), Direction::Cardinal, |&v| v != 9)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = Grid::single_ascii_number(INPUT);
+        let (labels, sizes) = grid.components(Direction::Cardinal, |&v| v != 9);
+        assert_eq!(sizes, vec![3, 9, 14, 9]);
+        assert_eq!(labels[(0, 0)], Some(0));
+        assert_eq!(labels[(2, 0)], None);
+        assert_eq!(labels[(9, 4)], Some(3));
+
+        let (_, sizes) = grid.components(Direction::Adjacent, |&v| v != 9);
+        assert_eq!(sizes.len(), 1);
+    }
+}
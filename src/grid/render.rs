@@ -0,0 +1,193 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+};
+
+use super::{Bounds, Grid, Point, SparseGrid};
+
+/// Draws a grid or a set of points as text.
+///
+/// Every position inside of the bounds is drawn as the character returned by
+/// the cell function, or a space if it returns [`None`].
+pub struct Render<'a> {
+    cell: Box<dyn Fn(isize, isize) -> Option<char> + 'a>,
+    bounds: Option<Bounds>,
+    axes: bool,
+    highlight: HashSet<Point<isize>>,
+    marker: char,
+}
+
+impl<'a> Render<'a> {
+    /// Draws every position inside of the bounds using `cell`.
+    pub fn new<F>(bounds: Option<Bounds>, cell: F) -> Self
+    where
+        F: Fn(isize, isize) -> Option<char> + 'a,
+    {
+        Self {
+            cell: Box::new(cell),
+            bounds,
+            axes: false,
+            highlight: HashSet::new(),
+            marker: '*',
+        }
+    }
+
+    /// Draws a set of points as `on` and everything else around them as `off`.
+    ///
+    /// The bounds are the smallest box around every point.
+    pub fn points<I>(points: I, on: char, off: char) -> Self
+    where
+        I: IntoIterator<Item = Point<isize>>,
+    {
+        let points: HashSet<_> = points.into_iter().collect();
+        let bounds = Bounds::around(points.iter().map(|p| (p.x, p.y)));
+        Self::new(bounds, move |x, y| {
+            Some(if points.contains(&Point::new(x, y)) {
+                on
+            } else {
+                off
+            })
+        })
+    }
+
+    /// Labels the rows and columns with their coordinates.
+    ///
+    /// Columns are labelled with the last digit of their coordinate.
+    pub fn axes(mut self) -> Self {
+        self.axes = true;
+        self
+    }
+
+    /// Draws the given points as `marker` instead of their usual character.
+    pub fn highlight<I>(mut self, points: I, marker: char) -> Self
+    where
+        I: IntoIterator<Item = Point<isize>>,
+    {
+        self.highlight.extend(points);
+        self.marker = marker;
+        self
+    }
+
+    /// Only draws the positions inside of the given bounds.
+    ///
+    /// The bounds can also be larger than what would normally be drawn.
+    pub fn crop(mut self, bounds: Bounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl<'a> Display for Render<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        let label = if self.axes {
+            let width = |y: isize| y.to_string().len();
+            width(bounds.min_y).max(width(bounds.max_y))
+        } else {
+            0
+        };
+        if self.axes {
+            write!(f, "{:width$} ", "", width = label)?;
+            for x in bounds.min_x..=bounds.max_x {
+                write!(f, "{}", x.rem_euclid(10))?;
+            }
+            f.write_char('\n')?;
+        }
+
+        for y in bounds.min_y..=bounds.max_y {
+            if self.axes {
+                write!(f, "{:>width$} ", y, width = label)?;
+            }
+            for x in bounds.min_x..=bounds.max_x {
+                let c = if self.highlight.contains(&Point::new(x, y)) {
+                    self.marker
+                } else {
+                    (self.cell)(x, y).unwrap_or(' ')
+                };
+                f.write_char(c)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<V> Grid<V> {
+    /// Draws the grid by turning every cell into a character.
+    pub fn render<'a, F>(&'a self, cell: F) -> Render<'a>
+    where
+        F: Fn(&V) -> char + 'a,
+    {
+        let bounds = (self.width > 0 && self.height > 0).then(|| Bounds {
+            min_x: 0,
+            min_y: 0,
+            max_x: self.width as isize - 1,
+            max_y: self.height as isize - 1,
+        });
+        Render::new(bounds, move |x, y| {
+            if x < 0 || y < 0 {
+                return None;
+            }
+            self.get(x as usize, y as usize).map(&cell)
+        })
+    }
+}
+
+impl<V> SparseGrid<V> {
+    /// Draws the bounds of the grid by turning every cell into a character.
+    pub fn render<'a, F>(&'a self, cell: F) -> Render<'a>
+    where
+        F: Fn(&V) -> char + 'a,
+    {
+        Render::new(self.bounds(), move |x, y| Some(cell(self.get(x, y))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::single_ascii_number("1163\n1381\n2136");
+        let odd = |v: &u8| if v % 2 == 1 { '#' } else { '.' };
+        assert_eq!(grid.render(odd).to_string(), "##.#\n##.#\n.##.\n");
+
+        let path = [(0, 0), (0, 1), (1, 1)].map(|(x, y)| Point::new(x, y));
+        let rendered = grid
+            .render(odd)
+            .highlight(path, 'o')
+            .crop(Bounds {
+                min_x: 0,
+                min_y: 0,
+                max_x: 4,
+                max_y: 1,
+            })
+            .axes();
+        assert_eq!(rendered.to_string(), "  01234\n0 o#.# \n1 oo.# \n");
+    }
+
+    #[test]
+    fn test_points() {
+        let points = [(-1, 2), (1, 3), (-1, 4)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(
+            Render::points(points, '#', '.').to_string(),
+            "#..\n..#\n#..\n"
+        );
+        assert_eq!(
+            Render::points(points, '#', '.').axes().to_string(),
+            "  901\n2 #..\n3 ..#\n4 #..\n"
+        );
+        assert_eq!(Render::points([], '#', '.').to_string(), "");
+
+        let mut sparse = SparseGrid::new(false);
+        sparse.insert(-2, -1, true);
+        sparse.insert(0, 0, true);
+        let rendered = sparse.render(|&v| if v { '#' } else { '.' });
+        assert_eq!(rendered.to_string(), "#..\n..#\n");
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Grid;
+
+/// How a grid is serialised, the boundary isn't kept.
+#[derive(Serialize)]
+struct RawRef<'a, V> {
+    width: usize,
+    height: usize,
+    data: &'a [V],
+}
+
+#[derive(Deserialize)]
+struct Raw<V> {
+    width: usize,
+    height: usize,
+    data: Vec<V>,
+}
+
+impl<V> Serialize for Grid<V>
+where
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawRef {
+            width: self.width,
+            height: self.height,
+            data: &self.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, V> Deserialize<'de> for Grid<V>
+where
+    V: Deserialize<'de>,
+{
+    /// Fails if there isn't a value for every cell.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Raw {
+            width,
+            height,
+            data,
+        } = Raw::deserialize(deserializer)?;
+        Grid::try_with_data(data, width, height).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Boundary, Direction};
+
+    #[test]
+    fn test_round_trip() {
+        let mut grid = Grid::single_ascii_number("5483\n2745\n5264").with_boundary(Boundary::Wrap);
+        grid[(1, 1)] = 0;
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"width":4,"height":3,"data":[5,4,8,3,2,0,4,5,5,2,6,4]}"#
+        );
+
+        let back: Grid<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!((back.width(), back.height()), (4, 3));
+        assert_eq!(back.as_vec(), grid.as_vec());
+        assert_eq!(back.boundary(), &Boundary::Clip);
+        assert_eq!(back.neighbours(0, 0, Direction::Cardinal).count(), 2);
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        let error = serde_json::from_str::<Grid<u8>>(r#"{"width":2,"height":2,"data":[1,2,3]}"#)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected 4 cells for a 2x2 grid"));
+
+        let error = serde_json::from_str::<Grid<u8>>(
+            r#"{"width":9223372036854775808,"height":2,"data":[]}"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("grid has too many cells"));
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Write},
+    ops::Index,
+};
+
+use super::{Direction, Grid, Point};
+
+/// The inclusive bounding box of the cells set in a [`SparseGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounds {
+    pub min_x: isize,
+    pub min_y: isize,
+    pub max_x: isize,
+    pub max_y: isize,
+}
+
+impl Bounds {
+    fn new(x: isize, y: isize) -> Self {
+        Self {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+        }
+    }
+
+    fn extend(&mut self, x: isize, y: isize) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// The smallest bounds around every position, or [`None`] if there aren't any.
+    pub fn around<I>(positions: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (isize, isize)>,
+    {
+        positions
+            .into_iter()
+            .fold(None, |bounds, (x, y)| match bounds {
+                Some(mut bounds) => {
+                    bounds.extend(x, y);
+                    Some(bounds)
+                }
+                None => Some(Self::new(x, y)),
+            })
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max_x - self.min_x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max_y - self.min_y) as usize + 1
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Every position inside of the bounds in row-major order.
+    pub fn coordinates(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (xs, ys) = (self.min_x..=self.max_x, self.min_y..=self.max_y);
+        ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+    }
+
+    /// The bounds grown by the given amount in every direction.
+    pub fn grow(&self, amount: isize) -> Self {
+        Self {
+            min_x: self.min_x - amount,
+            min_y: self.min_y - amount,
+            max_x: self.max_x + amount,
+            max_y: self.max_y + amount,
+        }
+    }
+}
+
+/// An unbounded grid with signed coordinates where only some cells are set.
+///
+/// Every cell that isn't set has the background value.
+#[derive(Clone)]
+pub struct SparseGrid<V> {
+    data: HashMap<(isize, isize), V>,
+    background: V,
+    bounds: Option<Bounds>,
+}
+
+impl<V> SparseGrid<V> {
+    /// Creates an empty grid where every cell has the background value.
+    pub fn new(background: V) -> Self {
+        Self {
+            data: HashMap::new(),
+            background,
+            bounds: None,
+        }
+    }
+
+    pub fn background(&self) -> &V {
+        &self.background
+    }
+
+    pub fn set_background(&mut self, background: V) {
+        self.background = background;
+    }
+
+    /// The bounding box of every set cell, or [`None`] if no cells are set.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The number of set cells.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Whether a cell is set, as opposed to having the background value.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.data.contains_key(&(x, y))
+    }
+
+    /// Returns the value of a cell, which is the background if it isn't set.
+    pub fn get(&self, x: isize, y: isize) -> &V {
+        self.data.get(&(x, y)).unwrap_or(&self.background)
+    }
+
+    /// Returns the value of a cell if it's set.
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut V> {
+        self.data.get_mut(&(x, y))
+    }
+
+    /// Returns the value of a cell, setting it to the background first if it isn't set.
+    pub fn cell_mut(&mut self, x: isize, y: isize) -> &mut V
+    where
+        V: Clone,
+    {
+        if !self.contains(x, y) {
+            self.insert(x, y, self.background.clone());
+        }
+        self.data.get_mut(&(x, y)).expect("cell was just set")
+    }
+
+    /// Sets a cell, returning the previous value if it was set.
+    pub fn insert(&mut self, x: isize, y: isize, value: V) -> Option<V> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(x, y),
+            None => self.bounds = Some(Bounds::new(x, y)),
+        }
+        self.data.insert((x, y), value)
+    }
+
+    /// Unsets a cell so it has the background value, returning the previous value if it was set.
+    pub fn remove(&mut self, x: isize, y: isize) -> Option<V> {
+        let value = self.data.remove(&(x, y))?;
+        let on_edge = self
+            .bounds
+            .is_some_and(|b| x == b.min_x || x == b.max_x || y == b.min_y || y == b.max_y);
+        if on_edge {
+            self.bounds = Bounds::around(self.data.keys().copied());
+        }
+        Some(value)
+    }
+
+    /// The neighbours of a cell in the given direction.
+    ///
+    /// The grid is unbounded so every neighbour is returned.
+    pub fn neighbours(
+        &self,
+        x: isize,
+        y: isize,
+        direction: Direction,
+    ) -> impl Iterator<Item = (isize, isize)> {
+        direction.as_iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Every set cell, in no particular order.
+    pub fn items(&self) -> impl Iterator<Item = ((isize, isize), &V)> {
+        self.data.iter().map(|(&p, v)| (p, v))
+    }
+
+    /// Every set cell, in no particular order.
+    pub fn items_mut(&mut self) -> impl Iterator<Item = ((isize, isize), &mut V)> {
+        self.data.iter_mut().map(|(&p, v)| (p, v))
+    }
+}
+
+impl<V> SparseGrid<V>
+where
+    V: Clone + PartialEq,
+{
+    /// Converts a dense grid, only setting the cells that differ from the background.
+    ///
+    /// The top left cell of the grid ends up at `(0, 0)`.
+    pub fn from_grid(grid: &Grid<V>, background: V) -> Self {
+        let mut result = Self::new(background);
+        for ((x, y), value) in grid.items() {
+            if *value != result.background {
+                result.insert(x as isize, y as isize, value.clone());
+            }
+        }
+        result
+    }
+}
+
+impl<V> SparseGrid<V>
+where
+    V: Clone,
+{
+    /// Converts the bounds of the grid into a dense grid.
+    ///
+    /// The top left cell of the dense grid is the top left corner of the bounds.
+    /// If no cells are set then the grid is empty.
+    pub fn to_grid(&self) -> Grid<V> {
+        match self.bounds {
+            Some(bounds) => {
+                let data = bounds
+                    .coordinates()
+                    .map(|(x, y)| self.get(x, y).clone())
+                    .collect();
+                Grid::with_data(data, bounds.width(), bounds.height())
+            }
+            None => Grid::with_data(Vec::new(), 0, 0),
+        }
+    }
+}
+
+impl<V> Index<(isize, isize)> for SparseGrid<V> {
+    type Output = V;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        self.get(index.0, index.1)
+    }
+}
+
+impl<V> Index<Point<isize>> for SparseGrid<V> {
+    type Output = V;
+
+    fn index(&self, index: Point<isize>) -> &Self::Output {
+        self.get(index.x, index.y)
+    }
+}
+
+impl<V> Debug for SparseGrid<V>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparseGrid")
+            .field("data", &self.data)
+            .field("background", &self.background)
+            .field("bounds", &self.bounds)
+            .finish()
+    }
+}
+
+impl<V> Display for SparseGrid<V>
+where
+    V: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(bounds) = self.bounds {
+            for y in bounds.min_y..=bounds.max_y {
+                for x in bounds.min_x..=bounds.max_x {
+                    self.get(x, y).fmt(f)?;
+                }
+                f.write_char('\n')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = SparseGrid::new(0);
+        assert!(grid.bounds().is_none());
+        grid.insert(-2, 3, 5);
+        grid.insert(4, -1, 7);
+        grid.insert(0, 0, 1);
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(-2, 3), &5);
+        assert_eq!(grid[(100, -100)], 0);
+        assert_eq!(grid[Point::new(-2, 3)], 5);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+            (-2, -1, 4, 3)
+        );
+        assert_eq!((bounds.width(), bounds.height()), (7, 5));
+
+        assert_eq!(grid.remove(4, -1), Some(7));
+        assert_eq!(grid.remove(4, -1), None);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+            (-2, 0, 0, 3)
+        );
+
+        grid.set_background(9);
+        assert_eq!(grid.get(4, -1), &9);
+        *grid.cell_mut(4, -1) += 1;
+        *grid.cell_mut(0, 0) += 1;
+        assert_eq!((grid.get(4, -1), grid.get(0, 0)), (&10, &2));
+        let neighbours: Vec<_> = grid.neighbours(0, 0, Direction::Cardinal).collect();
+        assert_eq!(neighbours, vec![(0, -1), (1, 0), (0, 1), (-1, 0)]);
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let dense = Grid::single_ascii_number("010\n002");
+        let sparse = SparseGrid::from_grid(&dense, 0);
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(2, 1), &2);
+
+        let back = sparse.to_grid();
+        assert_eq!((back.width(), back.height()), (2, 2));
+        assert_eq!(back.as_vec(), &vec![1, 0, 0, 2]);
+        assert_eq!(sparse.to_string(), "10\n02\n");
+
+        let empty: SparseGrid<u8> = SparseGrid::new(0);
+        assert_eq!(empty.to_grid().width(), 0);
+    }
+}
@@ -0,0 +1,140 @@
+use super::Grid;
+
+impl<V> Grid<V>
+where
+    V: Clone,
+{
+    /// Builds a new grid where every cell is copied from a cell of this one,
+    /// keeping the boundary.
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (sx, sy) = source(x, y);
+                data.push(self[(sx, sy)].clone());
+            }
+        }
+        Self::with_data(data, width, height).with_boundary(self.boundary.clone())
+    }
+
+    /// Swaps the rows and columns of the grid.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates the grid 90 degrees clockwise.
+    pub fn rotate_90(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates the grid 180 degrees.
+    pub fn rotate_180(&self) -> Self {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    /// Rotates the grid 270 degrees clockwise, i.e. 90 degrees counter-clockwise.
+    pub fn rotate_270(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors the grid from left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors the grid from top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+
+    /// Repeats the grid `across` times to the right and `down` times downwards.
+    ///
+    /// Every cell of each copy is passed through `f` along with the position of
+    /// the copy, where `(0, 0)` is the top left copy. The boundary is kept.
+    pub fn tile<F>(&self, across: usize, down: usize, mut f: F) -> Self
+    where
+        F: FnMut((usize, usize), &V) -> V,
+    {
+        let (width, height) = (self.width * across, self.height * down);
+        let mut data = Vec::with_capacity(width * height);
+        for ty in 0..down {
+            for y in 0..self.height {
+                for tx in 0..across {
+                    data.extend(self.row(y).iter().map(|value| f((tx, ty), value)));
+                }
+            }
+        }
+        Self::with_data(data, width, height).with_boundary(self.boundary.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Boundary;
+
+    fn grid() -> Grid<u8> {
+        // 1 2 3
+        // 4 5 6
+        Grid::single_ascii_number("123\n456")
+    }
+
+    fn check(grid: Grid<u8>, width: usize, height: usize, data: &[u8]) {
+        assert_eq!((grid.width(), grid.height()), (width, height));
+        assert_eq!(grid.as_vec(), data);
+    }
+
+    #[test]
+    fn test_transpose() {
+        check(grid().transpose(), 2, 3, &[1, 4, 2, 5, 3, 6]);
+        check(grid().transpose().transpose(), 3, 2, &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        check(grid().rotate_90(), 2, 3, &[4, 1, 5, 2, 6, 3]);
+        check(grid().rotate_180(), 3, 2, &[6, 5, 4, 3, 2, 1]);
+        check(grid().rotate_270(), 2, 3, &[3, 6, 2, 5, 1, 4]);
+        check(grid().rotate_90().rotate_270(), 3, 2, &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_flip() {
+        check(grid().flip_horizontal(), 3, 2, &[3, 2, 1, 6, 5, 4]);
+        check(grid().flip_vertical(), 3, 2, &[4, 5, 6, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_tile() {
+        let grid = Grid::single_ascii_number("12\n34");
+        check(
+            grid.tile(3, 2, |(tx, ty), &v| v + (tx + 10 * ty) as u8),
+            6,
+            4,
+            &[
+                1, 2, 2, 3, 3, 4, //
+                3, 4, 4, 5, 5, 6, //
+                11, 12, 12, 13, 13, 14, //
+                13, 14, 14, 15, 15, 16,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_boundary() {
+        let grid = grid().with_boundary(Boundary::Default(9));
+        assert_eq!(grid.rotate_90().boundary(), &Boundary::Default(9));
+        assert_eq!(grid.flip_vertical().at(-1, 0), Some(&9));
+        let grid = grid.with_boundary(Boundary::Wrap);
+        assert_eq!(grid.transpose().boundary(), &Boundary::Wrap);
+        assert_eq!(grid.tile(2, 2, |_, &v| v).boundary(), &Boundary::Wrap);
+    }
+}
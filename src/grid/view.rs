@@ -0,0 +1,306 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Column, Coordinates, Direction, Directional, Grid};
+
+/// A borrowed rectangular window into a [`Grid`].
+///
+/// Coordinates are relative to the top left corner of the window.
+pub struct GridView<'a, V> {
+    grid: &'a Grid<V>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+// derive would require V: Clone
+impl<'a, V> Clone for GridView<'a, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, V> Copy for GridView<'a, V> {}
+
+impl<'a, V> GridView<'a, V> {
+    pub(super) fn new(
+        grid: &'a Grid<V>,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        fits(grid, x, y, width, height).then_some(Self {
+            grid,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a V> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cells of a row of the window.
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &'a [V] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        let start = self.grid.index(self.x, self.y + y);
+        &self.grid.data[start..start + self.width]
+    }
+
+    /// Returns the cells of a column of the window from top to bottom.
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, x: usize) -> Column<'a, V> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        Column::new(self.grid, self.x + x, self.y, self.height)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [V]> {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'a, V>> {
+        let view = *self;
+        (0..self.width).map(move |x| view.column(x))
+    }
+
+    pub fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.width, self.height)
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = ((usize, usize), &'a V)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, value)| ((x, y), value))
+        })
+    }
+
+    /// The neighbours of a cell that are inside of the window.
+    pub fn neighbours(&self, x: usize, y: usize, direction: Direction) -> Directional {
+        Directional {
+            x: x as isize,
+            y: y as isize,
+            width: self.width as isize,
+            height: self.height as isize,
+            wrap: false,
+            iter: direction.as_iter(),
+        }
+    }
+
+    /// A smaller window inside of this one, relative to this one.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        (x + width <= self.width && y + height <= self.height).then_some(Self {
+            grid: self.grid,
+            x: self.x + x,
+            y: self.y + y,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'a, V> GridView<'a, V>
+where
+    V: Clone,
+{
+    /// Copies the window into its own grid.
+    pub fn to_grid(&self) -> Grid<V> {
+        let data = self.rows().flat_map(|row| row.iter().cloned()).collect();
+        Grid::with_data(data, self.width, self.height)
+    }
+}
+
+impl<'a, V> Index<(usize, usize)> for GridView<'a, V> {
+    type Output = V;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).expect("index out of bounds")
+    }
+}
+
+/// A mutably borrowed rectangular window into a [`Grid`].
+///
+/// Coordinates are relative to the top left corner of the window.
+pub struct GridViewMut<'a, V> {
+    grid: &'a mut Grid<V>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, V> GridViewMut<'a, V> {
+    pub(super) fn new(
+        grid: &'a mut Grid<V>,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        fits(grid, x, y, width, height).then_some(Self {
+            grid,
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Reborrows the window immutably.
+    pub fn as_view(&self) -> GridView<'_, V> {
+        GridView {
+            grid: self.grid,
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&V> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut V> {
+        if x < self.width && y < self.height {
+            self.grid.get_mut(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cells of a row of the window.
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> &mut [V] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        let start = self.grid.index(self.x, self.y + y);
+        &mut self.grid.data[start..start + self.width]
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [V]> {
+        let (x, width) = (self.x, self.width);
+        self.grid
+            .data
+            .chunks_mut(self.grid.width.max(1))
+            .skip(self.y)
+            .take(self.height)
+            .map(move |row| &mut row[x..x + width])
+    }
+
+    pub fn items_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut V)> {
+        self.rows_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, value)| ((x, y), value))
+        })
+    }
+
+    /// Sets every cell of the window to a value.
+    pub fn fill(&mut self, value: V)
+    where
+        V: Clone,
+    {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+}
+
+impl<'a, V> Index<(usize, usize)> for GridViewMut<'a, V> {
+    type Output = V;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).expect("index out of bounds")
+    }
+}
+
+impl<'a, V> IndexMut<(usize, usize)> for GridViewMut<'a, V> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index.0, index.1).expect("index out of bounds")
+    }
+}
+
+fn fits<V>(grid: &Grid<V>, x: usize, y: usize, width: usize, height: usize) -> bool {
+    x + width <= grid.width && y + height <= grid.height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::single_ascii_number("1234\n5678\n9012");
+        let view = grid.view(1, 1, 3, 2).unwrap();
+        assert_eq!((view.width(), view.height()), (3, 2));
+        assert_eq!(view[(0, 0)], 6);
+        assert_eq!(view.get(2, 1), Some(&2));
+        assert_eq!(view.get(3, 0), None);
+        assert_eq!(view.row(1), &[0, 1, 2]);
+        assert_eq!(view.column(2).copied().collect::<Vec<_>>(), vec![8, 2]);
+        assert_eq!(view.items().map(|(_, v)| *v as u32).sum::<u32>(), 24);
+        assert_eq!(view.neighbours(0, 0, Direction::Cardinal).count(), 2);
+
+        let inner = view.view(1, 0, 2, 2).unwrap();
+        assert_eq!(inner.to_grid().as_vec(), &vec![7, 8, 1, 2]);
+        assert!(view.view(2, 0, 2, 1).is_none());
+        assert!(grid.view(0, 0, 5, 1).is_none());
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut grid = Grid::single_ascii_number("1234\n5678\n9012");
+        let mut view = grid.view_mut(2, 0, 2, 3).unwrap();
+        view[(0, 0)] = 0;
+        view.row_mut(1)[1] = 0;
+        for ((x, y), value) in view.items_mut() {
+            if (x, y) == (1, 2) {
+                *value = 9;
+            }
+        }
+        assert_eq!(view.as_view().row(2), &[1, 9]);
+        assert_eq!(grid.as_vec(), &vec![1, 2, 0, 4, 5, 6, 7, 0, 9, 0, 1, 9]);
+
+        grid.view_mut(0, 1, 2, 2).unwrap().fill(3);
+        assert_eq!(grid.as_vec(), &vec![1, 2, 0, 4, 3, 3, 7, 0, 3, 3, 1, 9]);
+
+        let mut empty: Grid<u8> = Grid::with_data(Vec::new(), 0, 2);
+        assert_eq!(empty.view_mut(0, 0, 0, 2).unwrap().items_mut().count(), 0);
+        let empty: Grid<u8> = Grid::with_data(Vec::new(), 3, 0);
+        assert_eq!(empty.columns().map(|c| c.count()).sum::<usize>(), 0);
+        let view = empty.view(1, 0, 2, 0).unwrap();
+        assert_eq!(view.column(1).count(), 0);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// The directory puzzle inputs are read from if no other one is given.
+pub const DEFAULT_DIRECTORY: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// A directory with a `dayNN.txt` file for each day.
+    Directory(PathBuf),
+    /// A single file, regardless of the day.
+    File(PathBuf),
+    /// Standard input, regardless of the day.
+    Stdin,
+}
+
+impl Source {
+    /// Creates a source from a path, where `-` means standard input.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if path == Path::new("-") {
+            Self::Stdin
+        } else {
+            Self::File(path)
+        }
+    }
+
+    /// Returns the path of the input file for the given day.
+    ///
+    /// If the input comes from standard input then [`None`] is returned.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Directory(dir) => Some(dir.join(format!("day{:02}.txt", day))),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    /// Reads the puzzle input for the given day.
+    ///
+    /// Trailing whitespace is removed from the input.
+    pub fn read(&self, day: u8) -> Result<String> {
+        let mut input = match self.path(day) {
+            Some(path) => std::fs::read_to_string(&path).with_context(|| {
+                format!(
+                    "could not read the input for day {} from {}",
+                    day,
+                    path.display()
+                )
+            })?,
+            None => {
+                let mut buffer = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buffer)
+                    .context("could not read the input from stdin")?;
+                buffer
+            }
+        };
+
+        input.truncate(input.trim_end().len());
+        Ok(input)
+    }
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Self::Directory(PathBuf::from(DEFAULT_DIRECTORY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            Source::default().path(5),
+            Some(PathBuf::from("inputs/day05.txt"))
+        );
+        assert_eq!(
+            Source::file("day.txt").path(5),
+            Some(PathBuf::from("day.txt"))
+        );
+        assert_eq!(Source::file("-"), Source::Stdin);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = Source::Directory(PathBuf::from("does-not-exist"));
+        let error = source.read(7).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("day 7"));
+        assert!(message.contains("day07.txt"));
+    }
+
+    #[test]
+    fn test_trims_input() {
+        let path = std::env::temp_dir().join("aoc2021_test_trims_input.txt");
+        std::fs::write(&path, "1,2,3\n\n").unwrap();
+        assert_eq!(Source::file(&path).read(1).unwrap(), "1,2,3");
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,5 +1,21 @@
+pub mod answers;
+pub mod automaton;
+pub mod bench;
 mod counter;
+pub mod days;
+pub mod export;
 mod grid;
+pub mod input;
+pub mod ocr;
+pub mod parse;
+pub mod scaffold;
+pub mod search;
+mod solution;
 
 pub use counter::Counter;
-pub use grid::{Coordinates, Direction, Directional, FromCell, Grid, Items, ItemsMut};
+pub use grid::{
+    BitGrid, Boundary, Bounds, Column, Compass, Coordinates, Direction, Directional, FromCell,
+    Grid, GridError, GridView, GridViewMut, Items, ItemsMut, Point, Render, SparseGrid,
+    WithCompass,
+};
+pub use solution::{Answer, DynSolution, Solution};
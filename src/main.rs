@@ -1,3 +1,293 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc2021::{
+    answers::{self, Answers, Status},
+    bench::{self, Measurement},
+    days,
+    input::Source,
+    scaffold, Answer, DynSolution,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc2021", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    /// The directory containing the `dayNN.txt` puzzle inputs
+    #[arg(
+        long,
+        global = true,
+        env = "AOC_INPUTS",
+        default_value = aoc2021::input::DEFAULT_DIRECTORY
+    )]
+    inputs: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a single day or every day in sequence
+    Run {
+        /// The day to run, if not given then every day is ran
+        day: Option<u8>,
+        /// Only run the given part of the day
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file instead, or `-` for stdin
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Creates a new day from a template and registers it
+    NewDay {
+        /// The day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Overwrite the day if it already exists
+        #[arg(short, long)]
+        force: bool,
+        /// Import the puzzle input from this file
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Import the puzzle input from this directory of `dayNN.txt` files if it's there
+        #[arg(long, env = "AOC_CACHE")]
+        cache: Option<PathBuf>,
+    },
+    /// Runs every day and checks the answers against the known ones
+    Verify {
+        /// The file with the known answers, defaults to `answers.toml` in the inputs directory
+        #[arg(short, long)]
+        answers: Option<PathBuf>,
+    },
+    /// Times the parse, part 1 and part 2 phases of a single day or every day
+    Bench {
+        /// The day to benchmark, if not given then every day is benchmarked
+        day: Option<u8>,
+        /// The number of times each phase is ran
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Compare against the results of a previous `--json` run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How many percent slower a phase can get before it's flagged as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+#[inline]
+fn millis(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn run_day(solution: &dyn DynSolution, part: Option<u8>, source: &Source) -> Result<()> {
+    let day = solution.day();
+    let input = source.read(day)?;
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    for part in parts {
+        let t = Instant::now();
+        let answer = solution.run(part, &input)?;
+        let c = t.elapsed();
+        if let Some(answer) = answer {
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                println!("Day {:02} part {}: ({})", day, part, millis(c));
+                println!("{}", answer.trim_end());
+            } else {
+                println!("Day {:02} part {}: {} ({})", day, part, answer, millis(c));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A short single line description of an answer for tables.
+fn summary(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) if text.contains('\n') => format!("({} lines)", text.lines().count()),
+        answer => answer.to_string(),
+    }
+}
+
+fn verify(source: &Source, answers: &Answers) -> Result<()> {
+    let mut failures = 0;
+    println!(
+        "{:<5} {:<5} {:<8} {:>10}  Answer",
+        "Day", "Part", "Status", "Time"
+    );
+    for &solution in days::SOLUTIONS {
+        let day = solution.day();
+        let input = match source.read(day) {
+            Ok(input) => input,
+            Err(e) => {
+                failures += 1;
+                println!("{:<5} {:<5} {:<8} {:>10}  {:#}", day, "-", "error", "-", e);
+                continue;
+            }
+        };
+
+        for part in 1..=solution.parts() {
+            let t = Instant::now();
+            let answer = solution.run(part, &input);
+            let c = t.elapsed();
+            let time = millis(c);
+            let answer = match answer {
+                Ok(Some(answer)) => answer,
+                Ok(None) => continue,
+                Err(e) => {
+                    failures += 1;
+                    println!(
+                        "{:<5} {:<5} {:<8} {:>10}  {:#}",
+                        day, part, "error", time, e
+                    );
+                    continue;
+                }
+            };
+
+            let (status, details) = match answers.check(day, part, &answer) {
+                Status::Pass => ("pass", summary(&answer)),
+                Status::Missing => ("missing", summary(&answer)),
+                Status::Fail(expected) => {
+                    failures += 1;
+                    let details = format!("{} (expected {})", summary(&answer), summary(&expected));
+                    ("fail", details)
+                }
+            };
+            println!(
+                "{:<5} {:<5} {:<8} {:>10}  {}",
+                day, part, status, time, details
+            );
+        }
+    }
+
+    if failures > 0 {
+        bail!("{} answers did not pass", failures);
+    }
+    Ok(())
+}
+
+fn benchmark(
+    solutions: &[&dyn DynSolution],
+    source: &Source,
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    let mut measurements = Vec::new();
+    println!(
+        "{:<5} {:<6} {:>10} {:>10} {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean"
+    );
+    for solution in solutions {
+        let input = source.read(solution.day())?;
+        for m in solution.measure(&input, iterations)? {
+            println!(
+                "{:<5} {:<6} {:>10} {:>10} {:>10}",
+                m.day,
+                m.phase,
+                millis(m.stats.min),
+                millis(m.stats.median),
+                millis(m.stats.mean)
+            );
+            measurements.push(m);
+        }
+    }
+    Ok(measurements)
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let directory = Source::Directory(cli.inputs.clone());
+    match cli.command {
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+        } => {
+            let solution =
+                days::get(day).with_context(|| format!("day {} does not have a solution", day))?;
+            let source = input.map(Source::file).unwrap_or(directory);
+            run_day(solution, part, &source)?
+        }
+        Command::Run {
+            day: None, part, ..
+        } => {
+            let t = Instant::now();
+            for &solution in days::SOLUTIONS {
+                run_day(solution, part, &directory)?;
+            }
+            let c = t.elapsed();
+            println!("Total: {}", millis(c));
+        }
+        Command::NewDay {
+            day,
+            force,
+            input,
+            cache,
+        } => {
+            let options = scaffold::NewDay {
+                day,
+                force,
+                input,
+                cache,
+            };
+            for path in scaffold::create(Path::new("."), &cli.inputs, &options)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::Verify { answers } => {
+            let path = answers.unwrap_or_else(|| cli.inputs.join(answers::FILENAME));
+            let answers = Answers::load(&path)?;
+            verify(&directory, &answers)?;
+        }
+        Command::Bench {
+            day,
+            iterations,
+            json,
+            baseline,
+            threshold,
+        } => {
+            let solutions = match day {
+                Some(day) => vec![days::get(day)
+                    .with_context(|| format!("day {} does not have a solution", day))?],
+                None => days::SOLUTIONS.to_vec(),
+            };
+            let measurements = benchmark(&solutions, &directory, iterations)?;
+
+            if let Some(path) = json {
+                std::fs::write(&path, bench::to_json(&measurements, iterations))
+                    .with_context(|| format!("could not write {}", path.display()))?;
+            }
+
+            if let Some(path) = baseline {
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("could not read {}", path.display()))?;
+                let baseline = bench::from_json(&contents)
+                    .with_context(|| format!("could not parse {}", path.display()))?;
+                let regressions = bench::compare(&baseline, &measurements, threshold / 100.0);
+                for r in &regressions {
+                    println!(
+                        "Day {:02} {} regressed by {:.1}%: {} -> {}",
+                        r.day,
+                        r.phase,
+                        r.change * 100.0,
+                        millis(r.before),
+                        millis(r.after)
+                    );
+                }
+                if !regressions.is_empty() {
+                    bail!("{} phases regressed", regressions.len());
+                }
+            }
+        }
+    }
+    Ok(())
 }
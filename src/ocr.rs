@@ -0,0 +1,237 @@
+//! Reads the block letters that some puzzles draw as their answer.
+
+use crate::{Grid, Point};
+
+struct Font {
+    width: usize,
+    height: usize,
+    /// The number of empty columns between letters.
+    gap: usize,
+    /// Every letter along with its rows from top to bottom, separated by spaces.
+    glyphs: &'static [(char, &'static str)],
+}
+
+/// The 4x6 font, used by most puzzles including day 13.
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    gap: 1,
+    glyphs: &[
+        ('A', ".##. #..# #..# #### #..# #..#"),
+        ('B', "###. #..# ###. #..# #..# ###."),
+        ('C', ".##. #..# #... #... #..# .##."),
+        ('E', "#### #... ###. #... #... ####"),
+        ('F', "#### #... ###. #... #... #..."),
+        ('G', ".##. #..# #... #.## #..# .###"),
+        ('H', "#..# #..# #### #..# #..# #..#"),
+        ('I', ".### ..#. ..#. ..#. ..#. .###"),
+        ('J', "..## ...# ...# ...# #..# .##."),
+        ('K', "#..# #.#. ##.. #.#. #.#. #..#"),
+        ('L', "#... #... #... #... #... ####"),
+        ('O', ".##. #..# #..# #..# #..# .##."),
+        ('P', "###. #..# #..# ###. #... #..."),
+        ('R', "###. #..# #..# ###. #.#. #..#"),
+        ('S', ".### #... #... .##. ...# ###."),
+        ('U', "#..# #..# #..# #..# #..# .##."),
+        ('Y', "#... #... .#.# ..#. ..#. ..#."),
+        ('Z', "#### ...# ..#. .#.. #... ####"),
+    ],
+};
+
+/// The 6x10 font, used by some older puzzles.
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    gap: 2,
+    glyphs: &[
+        (
+            'A',
+            "..##.. .#..#. #....# #....# #....# ###### #....# #....# #....# #....#",
+        ),
+        (
+            'B',
+            "#####. #....# #....# #....# #####. #....# #....# #....# #....# #####.",
+        ),
+        (
+            'C',
+            ".####. #....# #..... #..... #..... #..... #..... #..... #....# .####.",
+        ),
+        (
+            'E',
+            "###### #..... #..... #..... #####. #..... #..... #..... #..... ######",
+        ),
+        (
+            'F',
+            "###### #..... #..... #..... #####. #..... #..... #..... #..... #.....",
+        ),
+        (
+            'G',
+            ".####. #....# #..... #..... #..... #..### #....# #....# #...## .###.#",
+        ),
+        (
+            'H',
+            "#....# #....# #....# #....# ###### #....# #....# #....# #....# #....#",
+        ),
+        (
+            'J',
+            "...### ....#. ....#. ....#. ....#. ....#. ....#. #...#. #...#. .###..",
+        ),
+        (
+            'K',
+            "#....# #...#. #..#.. #.#... ##.... ##.... #.#... #..#.. #...#. #....#",
+        ),
+        (
+            'L',
+            "#..... #..... #..... #..... #..... #..... #..... #..... #..... ######",
+        ),
+        (
+            'N',
+            "#....# ##...# ##...# #.#..# #.#..# #..#.# #..#.# #...## #...## #....#",
+        ),
+        (
+            'P',
+            "#####. #....# #....# #....# #####. #..... #..... #..... #..... #.....",
+        ),
+        (
+            'R',
+            "#####. #....# #....# #....# #####. #..#.. #...#. #...#. #....# #....#",
+        ),
+        (
+            'X',
+            "#....# #....# .#..#. .#..#. ..##.. ..##.. .#..#. .#..#. #....# #....#",
+        ),
+        (
+            'Z',
+            "###### .....# .....# ....#. ...#.. ..#... .#.... #..... #..... ######",
+        ),
+    ],
+};
+
+impl Font {
+    /// The letter whose top left corner is at the given column.
+    fn letter(&self, grid: &Grid<bool>, x: usize) -> Option<char> {
+        let mut glyph = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            if y != 0 {
+                glyph.push(' ');
+            }
+            for dx in 0..self.width {
+                let lit = grid.get(x + dx, y).copied().unwrap_or(false);
+                glyph.push(if lit { '#' } else { '.' });
+            }
+        }
+        self.glyphs
+            .iter()
+            .find(|(_, g)| *g == glyph)
+            .map(|&(letter, _)| letter)
+    }
+}
+
+/// Reads the letters drawn in a grid, starting from its top left corner.
+///
+/// The font is picked from the height of the grid. Returns [`None`] if the
+/// grid is empty or anything drawn isn't a known letter.
+pub fn read(grid: &Grid<bool>) -> Option<String> {
+    let font = match grid.height() {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+    if grid.width() == 0 {
+        return None;
+    }
+
+    (0..grid.width())
+        .step_by(font.width + font.gap)
+        .map(|x| font.letter(grid, x))
+        .collect()
+}
+
+/// Reads the letters drawn by a set of lit points, wherever they are.
+///
+/// Some letters start with an empty column, so if the letters can't be read
+/// from the leftmost point then the column before it is tried as well.
+pub fn read_points<I>(points: I) -> Option<String>
+where
+    I: IntoIterator<Item = Point<isize>>,
+{
+    let points: Vec<_> = points.into_iter().collect();
+    let min_x = points.iter().map(|p| p.x).min()?;
+    let min_y = points.iter().map(|p| p.y).min()?;
+    let max_x = points.iter().map(|p| p.x).max()?;
+    let max_y = points.iter().map(|p| p.y).max()?;
+
+    (0..2).find_map(|shift| {
+        let width = (max_x - min_x) as usize + 1 + shift;
+        let height = (max_y - min_y) as usize + 1;
+        let mut grid = Grid::new(width, height);
+        for p in &points {
+            grid[((p.x - min_x) as usize + shift, (p.y - min_y) as usize)] = true;
+        }
+        read(&grid)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws text in a font with `#` for lit cells, like the puzzles do.
+    fn draw(font: &Font, text: &str) -> String {
+        let mut rows = vec![String::new(); font.height];
+        for (i, c) in text.chars().enumerate() {
+            let (_, glyph) = font.glyphs.iter().find(|(l, _)| *l == c).unwrap();
+            for (row, line) in rows.iter_mut().zip(glyph.split(' ')) {
+                if i != 0 {
+                    row.push_str(&".".repeat(font.gap));
+                }
+                row.push_str(line);
+            }
+        }
+        rows.join("\n")
+    }
+
+    fn grid(s: &str) -> Grid<bool> {
+        let width = s.find('\n').unwrap();
+        let data: Vec<_> = s
+            .lines()
+            .flat_map(|l| l.chars())
+            .map(|c| c == '#')
+            .collect();
+        let height = data.len() / width;
+        Grid::with_data(data, width, height)
+    }
+
+    #[test]
+    fn test_read() {
+        let alphabet: String = SMALL.glyphs.iter().map(|(c, _)| c).collect();
+        assert_eq!(read(&grid(&draw(&SMALL, &alphabet))), Some(alphabet));
+        let alphabet: String = LARGE.glyphs.iter().map(|(c, _)| c).collect();
+        assert_eq!(read(&grid(&draw(&LARGE, &alphabet))), Some(alphabet));
+
+        assert_eq!(read(&grid("#...\n#...\n#...")), None);
+        assert_eq!(
+            read(&grid(&draw(&SMALL, "HI").replace("#..#.", "##.#."))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_points() {
+        let points = |s: &str| -> Vec<Point<isize>> {
+            grid(s)
+                .items()
+                .filter(|(_, &lit)| lit)
+                .map(|((x, y), _)| Point::new(x as isize + 40, y as isize - 3))
+                .collect()
+        };
+        assert_eq!(read_points(points(&draw(&SMALL, "EZ"))), Some("EZ".into()));
+        // the first column of `I` is empty
+        assert_eq!(
+            read_points(points(&draw(&SMALL, "IOU"))),
+            Some("IOU".into())
+        );
+        assert_eq!(read_points(points(&draw(&LARGE, "XN"))), Some("XN".into()));
+        assert_eq!(read_points([]), None);
+    }
+}
@@ -0,0 +1,392 @@
+use std::{error::Error, fmt::Display, ops::RangeInclusive, str::FromStr};
+
+#[doc(hidden)]
+pub use once_cell::sync::Lazy;
+pub use regex::Regex;
+
+/// An error that happened while parsing puzzle input.
+///
+/// This keeps track of where in the input the error happened along with the
+/// offending text so malformed input can be found easily.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    /// The line the error happened on, starting at 1.
+    pub line: usize,
+    /// The column the error happened on, starting at 1.
+    pub column: usize,
+    /// The text that could not be parsed.
+    pub text: String,
+    /// What went wrong.
+    pub message: String,
+}
+
+/// Returns the 1-based line and column of where `inner` starts inside `outer`.
+///
+/// If `inner` isn't a slice of `outer` then it's assumed to be at the start.
+fn position(outer: &str, inner: &str) -> (usize, usize) {
+    let start = outer.as_ptr() as usize;
+    let offset = (inner.as_ptr() as usize).wrapping_sub(start);
+    if offset > outer.len() {
+        return (1, 1);
+    }
+
+    let before = &outer[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = match before.rfind('\n') {
+        Some(index) => before[index + 1..].chars().count() + 1,
+        None => before.chars().count() + 1,
+    };
+    (line, column)
+}
+
+impl ParseError {
+    /// Creates an error about some text at the start of the input.
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error about `text`, which is a slice of `input`.
+    ///
+    /// The line and column are relative to the start of `input`.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, text);
+        Self {
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves the error from being relative to `inner` to being relative to `outer`,
+    /// where `inner` is a slice of `outer`.
+    ///
+    /// This is used when a piece of the input, such as a single line or section,
+    /// is parsed on its own.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (at `{}`)",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, into a value.
+///
+/// Errors point at `text` relative to the start of `input`.
+pub fn field<T>(input: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|e: T::Err| ParseError::at(input, text, e.to_string()))
+}
+
+/// Parses a value out of `inner`, a slice of `outer`, with errors relative to `outer`.
+///
+/// If the value's error type isn't a [`ParseError`] then the error points at
+/// the start of `inner`.
+pub fn within<T>(outer: &str, inner: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    inner.parse().map_err(
+        |e: T::Err| match anyhow::Error::new(e).downcast::<ParseError>() {
+            Ok(e) => e.within(outer, inner),
+            Err(e) => ParseError::at(outer, inner, e.to_string()),
+        },
+    )
+}
+
+/// Parses every line of the input into a value.
+///
+/// Errors carry the line number within `input`.
+pub fn lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    input.lines().map(|line| within(input, line)).collect()
+}
+
+/// Parses a list of values separated by `separator`, ignoring surrounding whitespace.
+pub fn separated<T>(input: &str, separator: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    input
+        .split(separator)
+        .map(|s| within(input, s.trim()))
+        .collect()
+}
+
+/// Parses a comma separated list of integers, such as `3,4,3,1,2`.
+pub fn ints<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    separated(input, ",")
+}
+
+/// Returns the blank line separated sections of the input.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n")
+}
+
+/// Splits the input into its first section and the rest of it.
+///
+/// This is the usual shape of an input with a header, such as day 4's drawn numbers.
+pub fn two_sections(input: &str) -> Result<(&str, &str), ParseError> {
+    input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(input, "expected two sections separated by a blank line"))
+}
+
+/// Parses a pair of values separated by `separator`, such as `0,9 -> 5,9`.
+pub fn pair<A, B>(input: &str, separator: &str) -> Result<(A, B), ParseError>
+where
+    A: FromStr,
+    A::Err: Error + Send + Sync + 'static,
+    B: FromStr,
+    B::Err: Error + Send + Sync + 'static,
+{
+    let (a, b) = input
+        .split_once(separator)
+        .ok_or_else(|| ParseError::new(input, format!("expected `{}`", separator)))?;
+    Ok((within(input, a)?, within(input, b)?))
+}
+
+/// Parses every line of the input as a pair of values separated by `separator`.
+pub fn pairs<A, B>(input: &str, separator: &str) -> Result<Vec<(A, B)>, ParseError>
+where
+    A: FromStr,
+    A::Err: Error + Send + Sync + 'static,
+    B: FromStr,
+    B::Err: Error + Send + Sync + 'static,
+{
+    input
+        .lines()
+        .map(|line| pair(line, separator).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+/// Parses every `key=lo..hi` range in the input in order, such as
+/// `target area: x=20..30, y=-10..-5`.
+pub fn ranges<T>(input: &str) -> Result<Vec<(&str, RangeInclusive<T>)>, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    regex!(r"(\w+)=([^.,\s]+)\.\.([^,\s]+)")
+        .captures_iter(input)
+        .map(|captures| {
+            let captures = Captures { input, captures };
+            let key = captures.str(1)?;
+            Ok((key, captures.parse(2)?..=captures.parse(3)?))
+        })
+        .collect()
+}
+
+/// Parses the `key=lo..hi` range with the given key.
+pub fn range<T>(input: &str, key: &str) -> Result<RangeInclusive<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    ranges(input)?
+        .into_iter()
+        .find_map(|(k, range)| (k == key).then_some(range))
+        .ok_or_else(|| ParseError::new(input, format!("missing the `{}` range", key)))
+}
+
+/// Returns a lazily compiled `&'static Regex` for a regex literal.
+///
+/// ```
+/// let regex = aoc2021::regex!(r"(\d+)-(\d+)");
+/// assert!(regex.is_match("1-3"));
+/// ```
+#[macro_export]
+macro_rules! regex {
+    ($re:literal $(,)?) => {{
+        static REGEX: $crate::parse::Lazy<$crate::parse::Regex> =
+            $crate::parse::Lazy::new(|| $crate::parse::Regex::new($re).unwrap());
+        &*REGEX
+    }};
+}
+
+pub use crate::regex;
+
+/// The capture groups of a regex match with errors relative to the input.
+#[derive(Debug)]
+pub struct Captures<'a> {
+    input: &'a str,
+    captures: regex::Captures<'a>,
+}
+
+impl<'a> Captures<'a> {
+    /// Returns the text of the given group.
+    pub fn str(&self, index: usize) -> Result<&'a str, ParseError> {
+        self.captures
+            .get(index)
+            .map(|m| m.as_str())
+            .ok_or_else(|| ParseError::new(self.input, format!("missing capture group {}", index)))
+    }
+
+    /// Parses the text of the given group into a value.
+    pub fn parse<T>(&self, index: usize) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Error + Send + Sync + 'static,
+    {
+        within(self.input, self.str(index)?)
+    }
+}
+
+/// Matches a regex against the whole input, failing if it doesn't match.
+pub fn captures<'a>(regex: &Regex, input: &'a str) -> Result<Captures<'a>, ParseError> {
+    let captures = regex
+        .captures(input)
+        .ok_or_else(|| ParseError::new(input, format!("expected a match for `{}`", regex)))?;
+    Ok(Captures { input, captures })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(i32, i32);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (x, y) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(s, "expected a comma"))?;
+            Ok(Self(field(s, x)?, field(s, y)?))
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let pairs: Vec<Pair> = lines("1,2\n3,4").unwrap();
+        assert_eq!(pairs, vec![Pair(1, 2), Pair(3, 4)]);
+
+        let numbers: Vec<u8> = lines("1\n2\n3").unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_error_location() {
+        let error = lines::<Pair>("1,2\n3,4\n5,x6").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "x6");
+
+        let error = lines::<Pair>("1,2\n34").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "34");
+        assert_eq!(error.message, "expected a comma");
+
+        let error = lines::<u8>("1\n2\n300").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "300");
+    }
+
+    #[test]
+    fn test_nested_location() {
+        let input = "header\n\n1,2\n3,y";
+        let (_, body) = input.split_once("\n\n").unwrap();
+        let error = lines::<Pair>(body)
+            .map_err(|e| e.within(input, body))
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 3: invalid digit found in string (at `y`)"
+        );
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<u8>("3,4,3,1,2").unwrap(), vec![3, 4, 3, 1, 2]);
+        assert_eq!(ints::<i32>("1, -2").unwrap(), vec![1, -2]);
+        assert_eq!(separated::<u8>("1 | 2", "|").unwrap(), vec![1, 2]);
+
+        let error = ints::<u8>("1,2,x,4").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "1,2,3\n\na\nb\n\nc";
+        assert_eq!(
+            sections(input).collect::<Vec<_>>(),
+            vec!["1,2,3", "a\nb", "c"]
+        );
+        assert_eq!(two_sections(input).unwrap(), ("1,2,3", "a\nb\n\nc"));
+        assert!(two_sections("1,2,3").is_err());
+    }
+
+    #[test]
+    fn test_pairs() {
+        assert_eq!(
+            pair::<String, char>("AB -> C", " -> ").unwrap(),
+            ("AB".into(), 'C')
+        );
+
+        let parsed: Vec<(Pair, Pair)> = pairs("0,9 -> 5,9\n8,0 -> 0,8", " -> ").unwrap();
+        assert_eq!(parsed[1], (Pair(8, 0), Pair(0, 8)));
+
+        let error = pairs::<Pair, Pair>("0,9 -> 5,9\n8,0 -> 0,z", " -> ").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+        assert!(pairs::<u8, u8>("1 2", " -> ").is_err());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let input = "target area: x=20..30, y=-10..-5";
+        let parsed = ranges::<i32>(input).unwrap();
+        assert_eq!(parsed, vec![("x", 20..=30), ("y", -10..=-5)]);
+        assert_eq!(range::<i32>(input, "y").unwrap(), -10..=-5);
+        assert!(range::<i32>(input, "z").is_err());
+
+        let error = range::<i32>("on x=1..2,y=3..4a", "x").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (16, "4a"));
+    }
+
+    #[test]
+    fn test_captures() {
+        let input = "on x=10..12";
+        let captures = captures(regex!(r"(on|off) x=(\S+)\.\.(\S+)"), input).unwrap();
+        assert_eq!(captures.str(1).unwrap(), "on");
+        assert_eq!(captures.parse::<i32>(3).unwrap(), 12);
+        assert!(captures.parse::<i32>(1).is_err());
+        assert!(captures.str(4).is_err());
+        assert!(super::captures(regex!(r"\d"), "abc").is_err());
+    }
+}
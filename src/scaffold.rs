@@ -0,0 +1,285 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+const TEMPLATE: &str = r#"use anyhow::Result;
+
+use crate::{Answer, Solution};
+
+pub fn part1(_lines: &[&str]) -> Result<usize> {
+    Ok(0)
+}
+
+pub fn part2(_lines: &[&str]) -> Result<usize> {
+    Ok(0)
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        {number}
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part1(input).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        part2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../fixtures/day{day}.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day{day}.parse(INPUT).unwrap()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day{day}.parse(INPUT).unwrap()).unwrap(), 0);
+    }
+}
+"#;
+
+/// Options for scaffolding a new day.
+#[derive(Debug, Clone, Default)]
+pub struct NewDay {
+    /// The day of the puzzle, from 1 to 25.
+    pub day: u8,
+    /// Whether to overwrite a day that already exists.
+    pub force: bool,
+    /// A puzzle input file to import.
+    pub input: Option<PathBuf>,
+    /// A directory of previously downloaded `dayNN.txt` puzzle inputs to import from.
+    pub cache: Option<PathBuf>,
+}
+
+/// Returns the source code of a new day's module.
+pub fn source(day: u8) -> String {
+    TEMPLATE
+        .replace("{day}", &format!("{:02}", day))
+        .replace("{number}", &day.to_string())
+}
+
+/// Adds a day to the source of the `days` module.
+///
+/// This adds both the `mod` declaration and the entry in the registry,
+/// keeping both sorted. Days that are already registered are left alone.
+pub fn register(registry: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("    &day{0:02}::Day{0:02},", day);
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    let modules: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(index, _)| index)
+        .collect();
+    let last = *modules.last().context("the registry has no modules")?;
+    if !lines.contains(&module) {
+        let index = modules
+            .iter()
+            .copied()
+            .find(|&index| lines[index] > module)
+            .unwrap_or(last + 1);
+        lines.insert(index, module);
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static SOLUTIONS"))
+        .context("the registry has no SOLUTIONS")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .context("the SOLUTIONS are never closed")?;
+    if !lines[start..end].contains(&entry) {
+        let index = (start + 1..end)
+            .find(|&index| lines[index] > entry)
+            .unwrap_or(end);
+        lines.insert(index, entry);
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    Ok(result)
+}
+
+fn find_input(options: &NewDay) -> Result<Option<PathBuf>> {
+    if let Some(path) = &options.input {
+        if !path.is_file() {
+            bail!("input file {} does not exist", path.display());
+        }
+        return Ok(Some(path.clone()));
+    }
+
+    if let Some(cache) = &options.cache {
+        let path = cache.join(format!("day{:02}.txt", options.day));
+        if path.is_file() {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+fn write(path: &Path, contents: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("could not create {}", parent.display()))?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))
+}
+
+/// Creates a new day in the crate at `root`, with its puzzle input going into `inputs`.
+///
+/// This writes the day's module, registers it and creates an empty example input fixture
+/// for its tests. If an input could be found it's copied into `inputs` as well.
+///
+/// Returns the paths of every file that was written.
+pub fn create(root: &Path, inputs: &Path, options: &NewDay) -> Result<Vec<PathBuf>> {
+    let day = options.day;
+    if !(1..=25).contains(&day) {
+        bail!("day {} is not between 1 and 25", day);
+    }
+
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    if module.exists() && !options.force {
+        bail!(
+            "day {} already exists at {}, use --force to overwrite it",
+            day,
+            module.display()
+        );
+    }
+
+    let input = find_input(options)?;
+    let mut written = Vec::new();
+
+    write(&module, source(day).as_bytes())?;
+    written.push(module);
+
+    let registry = root.join("src/days/mod.rs");
+    let contents = std::fs::read_to_string(&registry)
+        .with_context(|| format!("could not read {}", registry.display()))?;
+    write(&registry, register(&contents, day)?.as_bytes())?;
+    written.push(registry);
+
+    // fixtures are written by hand so even --force leaves them alone
+    let fixture = root.join(format!("fixtures/day{:02}.txt", day));
+    if !fixture.exists() {
+        write(&fixture, b"")?;
+        written.push(fixture);
+    }
+
+    if let Some(path) = input {
+        let contents =
+            std::fs::read(&path).with_context(|| format!("could not read {}", path.display()))?;
+        let destination = inputs.join(format!("day{:02}.txt", day));
+        write(&destination, &contents)?;
+        written.push(destination);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::DynSolution;
+
+pub mod day01;
+pub mod day03;
+
+/// Every day that currently has a solution, in order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_source() {
+        let source = source(7);
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("        7\n"));
+        assert!(source.contains("fixtures/day07.txt"));
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = register(REGISTRY, 2).unwrap();
+        assert_eq!(
+            registry,
+            "use crate::DynSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+/// Every day that currently has a solution, in order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+
+        let registry = register(&registry, 25).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day25;\n"));
+        assert!(registry.contains("    &day25::Day25,\n];"));
+
+        assert_eq!(register(&registry, 25).unwrap(), registry);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join("aoc2021_test_create");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        std::fs::create_dir_all(root.join("cache")).unwrap();
+        std::fs::write(root.join("cache/day02.txt"), "1,2,3\n").unwrap();
+
+        let mut options = NewDay {
+            day: 2,
+            cache: Some(root.join("cache")),
+            ..Default::default()
+        };
+        let inputs = root.join("inputs");
+        let written = create(&root, &inputs, &options).unwrap();
+        assert_eq!(written.len(), 4);
+        assert_eq!(
+            std::fs::read_to_string(inputs.join("day02.txt")).unwrap(),
+            "1,2,3\n"
+        );
+        assert!(root.join("fixtures/day02.txt").is_file());
+
+        assert!(create(&root, &inputs, &options).is_err());
+        std::fs::write(root.join("fixtures/day02.txt"), "example").unwrap();
+        options.force = true;
+        let written = create(&root, &inputs, &options).unwrap();
+        assert_eq!(written.len(), 3);
+        assert_eq!(
+            std::fs::read_to_string(root.join("fixtures/day02.txt")).unwrap(),
+            "example"
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{Direction, Grid};
+
+/// A path found by a search, from the start node to the goal node inclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    /// Returns the number of steps taken, which is one less than the number of nodes.
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+
+    /// Returns the goal node.
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("paths always have a start node")
+    }
+}
+
+/// Every node seen during a search, stored by index so the queue and
+/// parent links don't need to clone or order the nodes themselves.
+struct Visited<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    parents: Vec<usize>,
+    costs: Vec<C>,
+}
+
+impl<N, C> Visited<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new(start: N, cost: C) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            parents: vec![0],
+            costs: vec![cost],
+        }
+    }
+
+    /// Records a node if it's new or cheaper than before, returning its index if so.
+    fn relax(&mut self, node: N, parent: usize, cost: C) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                if cost >= self.costs[index] {
+                    return None;
+                }
+                self.parents[index] = parent;
+                self.costs[index] = cost;
+                Some(index)
+            }
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(index);
+                self.parents.push(parent);
+                self.costs.push(cost);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<N, C> {
+        let cost = self.costs[index];
+        let mut indices = vec![index];
+        while index != 0 {
+            index = self.parents[index];
+            indices.push(index);
+        }
+
+        let nodes = indices
+            .into_iter()
+            .rev()
+            .map(|i| self.nodes[i].clone())
+            .collect();
+        Path { nodes, cost }
+    }
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `goal` using A*.
+///
+/// `successors` returns the nodes reachable from a node along with the cost of
+/// moving to them. `heuristic` estimates the remaining cost to a goal and must
+/// never overestimate it, otherwise the path found might not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let zero = C::default();
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), zero, 0)));
+    let mut visited = Visited::new(start, zero);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > visited.costs[index] {
+            continue;
+        }
+        if goal(&visited.nodes[index]) {
+            return Some(visited.path(index));
+        }
+
+        for (node, step) in successors(&visited.nodes[index]) {
+            let estimate = heuristic(&node);
+            let next_cost = cost + step;
+            if let Some(next) = visited.relax(node, index, next_cost) {
+                queue.push(Reverse((next_cost + estimate, next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `goal` using Dijkstra's algorithm.
+///
+/// `successors` returns the nodes reachable from a node along with the cost of
+/// moving to them.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, goal: FG) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// Finds the path with the fewest steps from `start` to a node satisfying `goal`.
+///
+/// The cost of the returned path is its number of steps.
+pub fn bfs<N, FN, IN, FG>(start: N, mut successors: FN, mut goal: FG) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut queue = VecDeque::from([0]);
+    let mut visited = Visited::new(start, 0);
+
+    while let Some(index) = queue.pop_front() {
+        if goal(&visited.nodes[index]) {
+            return Some(visited.path(index));
+        }
+
+        let steps = visited.costs[index] + 1;
+        for node in successors(&visited.nodes[index]) {
+            if visited.indices.contains_key(&node) {
+                continue;
+            }
+            if let Some(next) = visited.relax(node, index, steps) {
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest path between two cells of a grid.
+///
+/// `cost` returns the cost of moving into a cell, or [`None`] if it can't be entered.
+pub fn grid_path<V, C, F>(
+    grid: &Grid<V>,
+    start: (usize, usize),
+    goal: (usize, usize),
+    direction: Direction,
+    mut cost: F,
+) -> Option<Path<(usize, usize), C>>
+where
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut((usize, usize), &V) -> Option<C>,
+{
+    dijkstra(
+        start,
+        |&(x, y)| {
+            grid.neighbours(x, y, direction)
+                .filter_map(|p| Some((p, cost(p, &grid[p])?)))
+                .collect::<Vec<_>>()
+        },
+        |&p| p == goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Grid<u8> {
+        Grid::single_ascii_number("1163\n1381\n2136")
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph: [(u8, u8, u32); 9] = [
+            (0, 1, 7),
+            (0, 2, 9),
+            (0, 5, 14),
+            (1, 2, 10),
+            (1, 3, 15),
+            (2, 3, 11),
+            (2, 5, 2),
+            (3, 4, 6),
+            (4, 5, 9),
+        ];
+        let successors = |&n: &u8| {
+            graph
+                .iter()
+                .filter_map(move |&(a, b, c)| {
+                    if n == a {
+                        Some((b, c))
+                    } else if n == b {
+                        Some((a, c))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(0, successors, |&n| n == 4).unwrap();
+        assert_eq!(path.cost, 20);
+        assert_eq!(path.nodes, vec![0, 2, 5, 4]);
+        assert_eq!(path.steps(), 3);
+        assert!(dijkstra(0, successors, |&n| n == 9).is_none());
+
+        let path = dijkstra(3, successors, |&n| n == 3).unwrap();
+        assert_eq!((path.cost, path.nodes), (0, vec![3]));
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (3, 2);
+        let grid = maze();
+        let path = astar(
+            (0, 0),
+            |&(x, y)| {
+                grid.neighbours(x, y, Direction::Cardinal)
+                    .map(|p| (p, grid[p] as u32))
+                    .collect::<Vec<_>>()
+            },
+            |&(x, y): &(usize, usize)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u32,
+            |&p| p == goal,
+        )
+        .unwrap();
+        let expected = grid_path(&grid, (0, 0), goal, Direction::Cardinal, |_, &v| {
+            Some(v as u32)
+        })
+        .unwrap();
+        assert_eq!(path.cost, 13);
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.goal(), &goal);
+    }
+
+    #[test]
+    fn test_bfs() {
+        // only cells with an odd value can be entered
+        let grid = maze();
+        let path = bfs(
+            (0, 0),
+            |&(x, y)| {
+                grid.neighbours(x, y, Direction::Cardinal)
+                    .filter(|&p| grid[p] % 2 == 1)
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == (2, 2),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes[0], (0, 0));
+        assert_eq!(path.goal(), &(2, 2));
+
+        let unreachable = bfs(
+            (0, 0),
+            |&(x, y)| {
+                grid.neighbours(x, y, Direction::Cardinal)
+                    .filter(|&p| grid[p] % 2 == 1)
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == (3, 1),
+        );
+        assert!(unreachable.is_none());
+
+        let blocked = grid_path(&grid, (0, 0), (3, 2), Direction::Cardinal, |_, &v| {
+            (v != 6).then_some(1)
+        });
+        assert!(blocked.is_none());
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Result};
+
+use crate::bench::{self, Measurement};
+
+/// The answer to a single part of a puzzle.
+///
+/// Most puzzles have numeric answers but some of them, such as day 13,
+/// have their answer drawn out as text.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Answer {
+    Int(i64),
+    /// A number too big for an [`Answer::Int`], so every number has a single representation.
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => value.fmt(f),
+            Answer::Unsigned(value) => value.fmt(f),
+            Answer::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_integers {
+    ($($e:ty)+) => {
+        $(
+            impl From<$e> for Answer {
+                #[inline]
+                fn from(value: $e) -> Self {
+                    Answer::Int(value as i64)
+                }
+            }
+        )+
+    };
+}
+
+impl_integers!(i64 i32 u32 i16 u16 i8 u8 isize);
+
+impl From<u64> for Answer {
+    #[inline]
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::Unsigned(value),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    #[inline]
+    fn from(value: usize) -> Self {
+        // usize is at most 64 bits on every supported platform
+        Answer::from(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    #[inline]
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// A single day's puzzle solution.
+///
+/// Every day parses its raw puzzle input, with trailing whitespace removed,
+/// once and then produces an [`Answer`] for each part from the parsed input.
+pub trait Solution: Sync {
+    /// The parsed puzzle input, which can borrow from the raw input.
+    type Input<'a>;
+
+    /// The day of the puzzle, from 1 to 25.
+    fn day(&self) -> u8;
+
+    /// The number of parts this puzzle has.
+    ///
+    /// This is always 2 except for the last day.
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer>;
+
+    fn part2(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+        bail!("day {} does not have a part 2", self.day())
+    }
+}
+
+/// A [`Solution`] with its parsed input hidden away, so that every day can be
+/// kept in the same registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> u8;
+
+    /// Parses the input and runs the given part of the puzzle on it.
+    ///
+    /// If the puzzle doesn't have that part then [`None`] is returned.
+    fn run(&self, part: u8, input: &str) -> Result<Option<Answer>>;
+
+    /// Times parsing and every part separately, see [`bench::measure`].
+    fn measure(&self, input: &str, iterations: usize) -> Result<Vec<Measurement>>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn parts(&self) -> u8 {
+        Solution::parts(self)
+    }
+
+    fn run(&self, part: u8, input: &str) -> Result<Option<Answer>> {
+        if part == 0 || part > Solution::parts(self) {
+            return Ok(None);
+        }
+        let input = self.parse(input)?;
+        match part {
+            1 => self.part1(&input).map(Some),
+            _ => self.part2(&input).map(Some),
+        }
+    }
+
+    fn measure(&self, input: &str, iterations: usize) -> Result<Vec<Measurement>> {
+        bench::measure(self, input, iterations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(1234usize).to_string(), "1234");
+        assert_eq!(Answer::from(-15i32).to_string(), "-15");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+        assert_eq!(Answer::from(40u16), Answer::Int(40));
+        assert_eq!(Answer::from(40u64), Answer::Int(40));
+        assert_eq!(Answer::from(u64::MAX), Answer::Unsigned(u64::MAX));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    }
+}